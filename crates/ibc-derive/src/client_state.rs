@@ -1,3 +1,4 @@
+mod any;
 mod traits;
 
 use darling::FromDeriveInput;
@@ -5,6 +6,7 @@ use proc_macro2::TokenStream;
 use quote::quote;
 use syn::DeriveInput;
 
+use any::impl_Any_conversions;
 use traits::{
     client_state_common::impl_ClientStateCommon, client_state_execution::impl_ClientStateExecution,
     client_state_validation::impl_ClientStateValidation,
@@ -17,6 +19,20 @@ pub(crate) struct Opts {
     client_validation_context: syn::ExprPath,
     #[darling(rename = "ClientExecutionContext")]
     client_execution_context: syn::ExprPath,
+    /// The generic parameter used as the contexts, along with its trait bounds
+    /// (e.g. `generic = "Ctx: MyBound"`)
+    #[darling(default)]
+    generic: Option<String>,
+}
+
+impl Opts {
+    /// Parses the generic parameter of the contexts, if any.
+    pub(crate) fn generic_param(&self) -> Option<syn::TypeParam> {
+        self.generic.as_ref().map(|generic| {
+            syn::parse_str(generic)
+                .unwrap_or_else(|e| panic!("invalid generic parameter `{generic}`: {e}"))
+        })
+    }
 }
 
 pub fn client_state_derive_impl(ast: DeriveInput) -> TokenStream {
@@ -39,6 +55,7 @@ pub fn client_state_derive_impl(ast: DeriveInput) -> TokenStream {
         impl_ClientStateValidation(enum_name, enum_variants, &opts);
     let ClientStateExecution_impl_block =
         impl_ClientStateExecution(enum_name, enum_variants, &opts);
+    let Any_conversions_impl_block = impl_Any_conversions(enum_name, enum_variants);
 
     let maybe_extern_crate_stmt = if is_mock(&ast) {
         // Note: we must add this statement when in "mock mode"
@@ -55,6 +72,7 @@ pub fn client_state_derive_impl(ast: DeriveInput) -> TokenStream {
         #ClientStateCommon_impl_block
         #ClientStateValidation_impl_block
         #ClientStateExecution_impl_block
+        #Any_conversions_impl_block
     }
 }
 
@@ -76,3 +94,87 @@ fn is_mock(ast: &DeriveInput) -> bool {
 
     false
 }
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+    use syn::{parse_quote, DeriveInput};
+
+    use super::client_state_derive_impl;
+    use crate::utils::Imports;
+
+    fn generic_client_state_enum() -> DeriveInput {
+        parse_quote! {
+            #[generics(ClientValidationContext = Ctx,
+                       ClientExecutionContext = Ctx,
+                       generic = "Ctx: MyValidationContext + MyExecutionContext")
+            ]
+            enum HostClientState {
+                Tendermint(TmClientState),
+                Mock(MockClientState),
+            }
+        }
+    }
+
+    #[test]
+    fn generic_context_is_declared_on_validation_impl() {
+        let expanded = client_state_derive_impl(generic_client_state_enum()).to_string();
+
+        let ClientStateValidation = Imports::ClientStateValidation();
+        let expected = quote! {
+            impl<Ctx: MyValidationContext + MyExecutionContext> #ClientStateValidation<Ctx> for HostClientState
+            where
+                TmClientState: #ClientStateValidation<Ctx>,
+                MockClientState: #ClientStateValidation<Ctx>
+        };
+
+        assert!(
+            expanded.contains(&expected.to_string()),
+            "unexpected expansion: {expanded}"
+        );
+    }
+
+    #[test]
+    fn generic_context_is_declared_on_execution_impl() {
+        let expanded = client_state_derive_impl(generic_client_state_enum()).to_string();
+
+        let ClientStateExecution = Imports::ClientStateExecution();
+        let ClientExecutionContext = Imports::ClientExecutionContext();
+        let expected = quote! {
+            impl<Ctx: MyValidationContext + MyExecutionContext> #ClientStateExecution<Ctx> for HostClientState
+            where
+                Ctx: #ClientExecutionContext,
+                TmClientState: #ClientStateExecution<Ctx>,
+                MockClientState: #ClientStateExecution<Ctx>
+        };
+
+        assert!(
+            expanded.contains(&expected.to_string()),
+            "unexpected expansion: {expanded}"
+        );
+    }
+
+    #[test]
+    fn no_generic_context_by_default() {
+        let ast: DeriveInput = parse_quote! {
+            #[generics(ClientValidationContext = MyContext,
+                       ClientExecutionContext = MyContext)
+            ]
+            enum HostClientState {
+                Tendermint(TmClientState),
+            }
+        };
+
+        let expanded = client_state_derive_impl(ast).to_string();
+
+        let ClientStateValidation = Imports::ClientStateValidation();
+        let expected = quote! {
+            impl #ClientStateValidation<MyContext> for HostClientState
+        };
+
+        assert!(
+            expanded.contains(&format!("{expected} {{")),
+            "unexpected expansion: {expanded}"
+        );
+    }
+}
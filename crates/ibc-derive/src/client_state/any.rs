@@ -0,0 +1,177 @@
+//! Hosts the code generation of the conversions between the client state enum
+//! and `Any`
+
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{punctuated::Punctuated, token::Comma, Variant};
+
+use crate::utils::{get_enum_variant_type_path, get_enum_variant_type_urls, Imports};
+
+/// Generates `From<HostClientState> for Any`, `TryFrom<Any> for
+/// HostClientState` and a `verify_client_state_type` helper from the
+/// `#[type_url = "..."]` attributes of the enum variants. Nothing is generated
+/// if no variant is annotated.
+pub(crate) fn impl_Any_conversions(
+    client_state_enum_name: &Ident,
+    enum_variants: &Punctuated<Variant, Comma>,
+) -> TokenStream {
+    let type_urls = match get_enum_variant_type_urls(client_state_enum_name, enum_variants.iter()) {
+        Some(type_urls) => type_urls,
+        None => return quote! {},
+    };
+
+    let Any = Imports::Any();
+    let ClientError = Imports::ClientError();
+
+    let into_any_arms = type_urls.iter().map(|(variant, _)| {
+        let variant_name = &variant.ident;
+
+        quote! {
+            #client_state_enum_name::#variant_name(cs) => #Any::from(cs)
+        }
+    });
+
+    let try_from_any_arms = type_urls.iter().map(|(variant, type_url)| {
+        let variant_name = &variant.ident;
+        let variant_type_name = get_enum_variant_type_path(variant);
+
+        quote! {
+            #type_url => Ok(#client_state_enum_name::#variant_name(
+                <#variant_type_name as TryFrom<#Any>>::try_from(any)?
+            ))
+        }
+    });
+
+    let known_type_urls = type_urls.iter().map(|(_, type_url)| type_url);
+
+    quote! {
+        impl #client_state_enum_name {
+            /// Checks that the type URL of `any` is the one of a variant,
+            /// without decoding it.
+            pub fn verify_client_state_type(any: &#Any) -> Result<(), #ClientError> {
+                match any.type_url.as_str() {
+                    #(#known_type_urls)|* => Ok(()),
+                    _ => Err(#ClientError::UnknownClientStateType {
+                        client_state_type: any.type_url.clone(),
+                    }),
+                }
+            }
+        }
+
+        impl From<#client_state_enum_name> for #Any {
+            fn from(client_state: #client_state_enum_name) -> Self {
+                match client_state {
+                    #(#into_any_arms),*
+                }
+            }
+        }
+
+        impl TryFrom<#Any> for #client_state_enum_name {
+            type Error = #ClientError;
+
+            fn try_from(any: #Any) -> Result<Self, Self::Error> {
+                match any.type_url.as_str() {
+                    #(#try_from_any_arms,)*
+                    _ => Err(#ClientError::UnknownClientStateType {
+                        client_state_type: any.type_url,
+                    }),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+    use syn::{parse_quote, DeriveInput};
+
+    use super::impl_Any_conversions;
+    use crate::utils::Imports;
+
+    fn client_state_enum() -> DeriveInput {
+        parse_quote! {
+            enum HostClientState {
+                #[type_url = "/ibc.lightclients.tendermint.v1.ClientState"]
+                Tendermint(TmClientState),
+                #[type_url = "/ibc.mock.ClientState"]
+                Mock(MockClientState),
+            }
+        }
+    }
+
+    fn expand(ast: DeriveInput) -> String {
+        let enum_variants = match ast.data {
+            syn::Data::Enum(enum_data) => enum_data.variants,
+            _ => unreachable!("the client state is an enum"),
+        };
+
+        impl_Any_conversions(&ast.ident, &enum_variants).to_string()
+    }
+
+    #[test]
+    fn from_client_state_for_any() {
+        let expanded = expand(client_state_enum());
+
+        let Any = Imports::Any();
+        let expected = quote! {
+            impl From<HostClientState> for #Any {
+                fn from(client_state: HostClientState) -> Self {
+                    match client_state {
+                        HostClientState::Tendermint(cs) => #Any::from(cs),
+                        HostClientState::Mock(cs) => #Any::from(cs)
+                    }
+                }
+            }
+        };
+
+        assert!(
+            expanded.contains(&expected.to_string()),
+            "unexpected expansion: {expanded}"
+        );
+    }
+
+    #[test]
+    fn try_from_any_for_client_state() {
+        let expanded = expand(client_state_enum());
+
+        let Any = Imports::Any();
+        let ClientError = Imports::ClientError();
+        let expected = quote! {
+            impl TryFrom<#Any> for HostClientState {
+                type Error = #ClientError;
+
+                fn try_from(any: #Any) -> Result<Self, Self::Error> {
+                    match any.type_url.as_str() {
+                        "/ibc.lightclients.tendermint.v1.ClientState" => Ok(HostClientState::Tendermint(
+                            <TmClientState as TryFrom<#Any>>::try_from(any)?
+                        )),
+                        "/ibc.mock.ClientState" => Ok(HostClientState::Mock(
+                            <MockClientState as TryFrom<#Any>>::try_from(any)?
+                        )),
+                        _ => Err(#ClientError::UnknownClientStateType {
+                            client_state_type: any.type_url,
+                        }),
+                    }
+                }
+            }
+        };
+
+        assert!(
+            expanded.contains(&expected.to_string()),
+            "unexpected expansion: {expanded}"
+        );
+    }
+
+    #[test]
+    fn no_conversions_without_type_urls() {
+        let expanded = expand(parse_quote! {
+            enum HostClientState {
+                Tendermint(TmClientState),
+                Mock(MockClientState),
+            }
+        });
+
+        assert!(expanded.is_empty(), "unexpected expansion: {expanded}");
+    }
+}
@@ -51,9 +51,26 @@ pub(crate) fn impl_ClientStateExecution(
     let ClientStateExecution = Imports::ClientStateExecution();
     let UpdateKind = Imports::UpdateKind();
     let Height = Imports::Height();
+    let ClientExecutionContextTrait = Imports::ClientExecutionContext();
+
+    // With a generic context, every variant must be executable with that context.
+    let (impl_generics, where_clause) = match opts.generic_param() {
+        Some(generic_param) => {
+            let bounds = enum_variants.iter().map(|variant| {
+                let TmClientState = get_enum_variant_type_path(variant);
+                quote! { #TmClientState: #ClientStateExecution<#ClientExecutionContext> }
+            });
+
+            (
+                quote! { <#generic_param> },
+                quote! { where #ClientExecutionContext: #ClientExecutionContextTrait, #(#bounds),* },
+            )
+        }
+        None => (quote! {}, quote! {}),
+    };
 
     quote! {
-        impl #ClientStateExecution<#ClientExecutionContext> for #HostClientState {
+        impl #impl_generics #ClientStateExecution<#ClientExecutionContext> for #HostClientState #where_clause {
             fn initialise(
                 &self,
                 ctx: &mut #ClientExecutionContext,
@@ -39,8 +39,21 @@ pub(crate) fn impl_ClientStateValidation(
     let ClientStateValidation = Imports::ClientStateValidation();
     let UpdateKind = Imports::UpdateKind();
 
+    // With a generic context, every variant must be valid for that context.
+    let (impl_generics, where_clause) = match opts.generic_param() {
+        Some(generic_param) => {
+            let bounds = enum_variants.iter().map(|variant| {
+                let TmClientState = get_enum_variant_type_path(variant);
+                quote! { #TmClientState: #ClientStateValidation<#ClientValidationContext> }
+            });
+
+            (quote! { <#generic_param> }, quote! { where #(#bounds),* })
+        }
+        None => (quote! {}, quote! {}),
+    };
+
     quote! {
-        impl #ClientStateValidation<#ClientValidationContext> for #HostClientState {
+        impl #impl_generics #ClientStateValidation<#ClientValidationContext> for #HostClientState #where_clause {
             fn verify_client_message(
                 &self,
                 ctx: &#ClientValidationContext,
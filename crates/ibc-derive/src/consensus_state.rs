@@ -2,7 +2,7 @@ use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{punctuated::Iter, DeriveInput, Ident, Variant};
 
-use crate::utils::{get_enum_variant_type_path, Imports};
+use crate::utils::{get_enum_variant_type_path, get_enum_variant_type_urls, Imports};
 
 pub fn consensus_state_derive_impl(ast: DeriveInput) -> TokenStream {
     let enum_name = &ast.ident;
@@ -17,6 +17,8 @@ pub fn consensus_state_derive_impl(ast: DeriveInput) -> TokenStream {
     let encode_vec_impl =
         delegate_call_in_match(enum_name, enum_variants.iter(), quote! {encode_vec(cs)});
 
+    let decode_any_impl = impl_decode_any(enum_name, enum_variants.iter());
+
     let CommitmentRoot = Imports::CommitmentRoot();
     let ConsensusState = Imports::ConsensusState();
     let Timestamp = Imports::Timestamp();
@@ -41,6 +43,45 @@ pub fn consensus_state_derive_impl(ast: DeriveInput) -> TokenStream {
                 }
             }
         }
+
+        #decode_any_impl
+    }
+}
+
+/// Generates a `decode_any` dispatcher from the `#[type_url = "..."]` attributes
+/// of the enum variants. Nothing is generated if no variant is annotated.
+fn impl_decode_any(enum_name: &Ident, enum_variants: Iter<'_, Variant>) -> TokenStream {
+    let type_urls = match get_enum_variant_type_urls(enum_name, enum_variants) {
+        Some(type_urls) => type_urls,
+        None => return quote! {},
+    };
+
+    let Any = Imports::Any();
+    let ClientError = Imports::ClientError();
+
+    let decode_arms = type_urls.iter().map(|(variant, type_url)| {
+        let variant_name = &variant.ident;
+        let variant_type_name = get_enum_variant_type_path(variant);
+
+        quote! {
+            #type_url => Ok(#enum_name::#variant_name(
+                <#variant_type_name as TryFrom<#Any>>::try_from(any)?
+            ))
+        }
+    });
+
+    quote! {
+        impl #enum_name {
+            /// Decodes the variant whose type URL matches the one of `any`.
+            pub fn decode_any(any: #Any) -> Result<Self, #ClientError> {
+                match any.type_url.as_str() {
+                    #(#decode_arms,)*
+                    _ => Err(#ClientError::UnknownConsensusStateType {
+                        consensus_state_type: any.type_url,
+                    }),
+                }
+            }
+        }
     }
 }
 
@@ -62,3 +103,66 @@ fn delegate_call_in_match(
         })
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+    use syn::{parse_quote, DeriveInput};
+
+    use super::consensus_state_derive_impl;
+    use crate::utils::Imports;
+
+    #[test]
+    fn decode_any_dispatches_on_type_urls() {
+        let ast: DeriveInput = parse_quote! {
+            enum HostConsensusState {
+                #[type_url = "/ibc.lightclients.tendermint.v1.ConsensusState"]
+                Tendermint(TmConsensusState),
+                #[type_url = "/ibc.mock.ConsensusState"]
+                Mock(MockConsensusState),
+            }
+        };
+
+        let expanded = consensus_state_derive_impl(ast).to_string();
+
+        let Any = Imports::Any();
+        let ClientError = Imports::ClientError();
+        let expected = quote! {
+            pub fn decode_any(any: #Any) -> Result<Self, #ClientError> {
+                match any.type_url.as_str() {
+                    "/ibc.lightclients.tendermint.v1.ConsensusState" => Ok(HostConsensusState::Tendermint(
+                        <TmConsensusState as TryFrom<#Any>>::try_from(any)?
+                    )),
+                    "/ibc.mock.ConsensusState" => Ok(HostConsensusState::Mock(
+                        <MockConsensusState as TryFrom<#Any>>::try_from(any)?
+                    )),
+                    _ => Err(#ClientError::UnknownConsensusStateType {
+                        consensus_state_type: any.type_url,
+                    }),
+                }
+            }
+        };
+
+        assert!(
+            expanded.contains(&expected.to_string()),
+            "unexpected expansion: {expanded}"
+        );
+    }
+
+    #[test]
+    fn no_decode_any_without_type_urls() {
+        let ast: DeriveInput = parse_quote! {
+            enum HostConsensusState {
+                Tendermint(TmConsensusState),
+                Mock(MockConsensusState),
+            }
+        };
+
+        let expanded = consensus_state_derive_impl(ast).to_string();
+
+        assert!(
+            !expanded.contains("decode_any"),
+            "unexpected expansion: {expanded}"
+        );
+    }
+}
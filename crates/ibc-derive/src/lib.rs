@@ -17,7 +17,7 @@ use consensus_state::consensus_state_derive_impl;
 use proc_macro::TokenStream as RawTokenStream;
 use syn::{parse_macro_input, DeriveInput};
 
-#[proc_macro_derive(ClientState, attributes(generics, mock))]
+#[proc_macro_derive(ClientState, attributes(generics, mock, type_url))]
 pub fn client_state_macro_derive(input: RawTokenStream) -> RawTokenStream {
     let ast: DeriveInput = parse_macro_input!(input);
 
@@ -26,7 +26,7 @@ pub fn client_state_macro_derive(input: RawTokenStream) -> RawTokenStream {
     RawTokenStream::from(output)
 }
 
-#[proc_macro_derive(ConsensusState)]
+#[proc_macro_derive(ConsensusState, attributes(type_url))]
 pub fn consensus_state_macro_derive(input: RawTokenStream) -> RawTokenStream {
     let ast: DeriveInput = parse_macro_input!(input);
 
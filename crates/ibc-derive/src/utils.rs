@@ -1,6 +1,6 @@
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Path, Variant};
+use syn::{punctuated::Iter, Expr, Ident, Lit, LitStr, Meta, Path, Variant};
 
 /// Encodes the ibc-rs types that will be used in the macro
 ///
@@ -41,6 +41,10 @@ impl Imports {
         quote! {ibc::core::ics02_client::client_state::ClientStateExecution}
     }
 
+    pub fn ClientExecutionContext() -> TokenStream {
+        quote! {ibc::core::ics02_client::ClientExecutionContext}
+    }
+
     pub fn ClientId() -> TokenStream {
         quote! {ibc::core::ics24_host::identifier::ClientId}
     }
@@ -105,3 +109,48 @@ pub fn get_enum_variant_type_path(enum_variant: &Variant) -> &Path {
         }
     }
 }
+
+/// Retrieves the `#[type_url = "..."]` attribute of every enum variant, for the
+/// macros that dispatch on the protobuf type URL of the variants.
+///
+/// Returns `None` if no variant is annotated, and outputs an error message if
+/// only some of them are, as the dispatch would silently miss those variants.
+pub fn get_enum_variant_type_urls<'a>(
+    enum_name: &Ident,
+    enum_variants: Iter<'a, Variant>,
+) -> Option<Vec<(&'a Variant, &'a LitStr)>> {
+    let variants_count = enum_variants.len();
+    let type_urls: Vec<_> = enum_variants
+        .filter_map(|variant| get_type_url(variant).map(|type_url| (variant, type_url)))
+        .collect();
+
+    if type_urls.is_empty() {
+        return None;
+    }
+
+    if type_urls.len() != variants_count {
+        panic!("either all or none of the {enum_name} variants must have a #[type_url = \"...\"] attribute");
+    }
+
+    Some(type_urls)
+}
+
+/// Retrieves the value of the `#[type_url = "..."]` attribute of an enum variant, if any.
+fn get_type_url(enum_variant: &Variant) -> Option<&LitStr> {
+    let variant_name = &enum_variant.ident;
+
+    enum_variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("type_url"))
+        .map(|attr| match &attr.meta {
+            Meta::NameValue(name_value) => match &name_value.value {
+                Expr::Lit(expr_lit) => match &expr_lit.lit {
+                    Lit::Str(type_url) => type_url,
+                    _ => panic!("\"{variant_name}\" type URL must be a string literal"),
+                },
+                _ => panic!("\"{variant_name}\" type URL must be a string literal"),
+            },
+            _ => panic!("\"{variant_name}\" type URL must be specified as #[type_url = \"...\"]"),
+        })
+}
@@ -5,9 +5,15 @@ use derive_more::{Display, From, Into};
 
 use super::error::TokenTransferError;
 use primitive_types::U256;
+use uint::{FromDecStrErr, FromStrRadixErrKind};
+
+#[cfg(feature = "serde")]
+use crate::serializers::serde_string;
 
 /// A type for representing token transfer amounts.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+///
+/// With the `serde` feature, amounts are (de)serialized as decimal strings,
+/// as in the ICS20 packet data JSON, to avoid overflowing JSON numbers.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord, Display, From, Into)]
 pub struct Amount(U256);
 
@@ -19,6 +25,26 @@ impl parity_scale_codec::WrapperTypeDecode for Amount {
 #[cfg(feature = "parity-scale-codec")]
 impl parity_scale_codec::WrapperTypeEncode for Amount {}
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for Amount {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde_string::serialize(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Amount {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        serde_string::deserialize(deserializer)
+    }
+}
+
 impl Deref for Amount {
     type Target = [u64; 4];
 
@@ -34,6 +60,14 @@ impl From<[u64; 4]> for Amount {
 }
 
 impl Amount {
+    pub fn as_u256(&self) -> U256 {
+        self.0
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
     pub fn checked_add(self, rhs: Self) -> Option<Self> {
         self.0.checked_add(rhs.0).map(Self)
     }
@@ -41,6 +75,36 @@ impl Amount {
     pub fn checked_sub(self, rhs: Self) -> Option<Self> {
         self.0.checked_sub(rhs.0).map(Self)
     }
+
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        self.0.checked_mul(rhs.0).map(Self)
+    }
+
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.0.checked_div(rhs.0).map(Self)
+    }
+
+    /// Parses a hexadecimal amount prefixed with `0x`/`0X`.
+    ///
+    /// Unlike `from_str`, which only accepts decimal strings as in the ICS20
+    /// packet data, this is meant for tooling that encodes amounts as hex.
+    pub fn from_hex_str(s: &str) -> Result<Self, TokenTransferError> {
+        let hex = s
+            .strip_prefix("0x")
+            .or_else(|| s.strip_prefix("0X"))
+            .filter(|hex| !hex.is_empty())
+            .ok_or(TokenTransferError::InvalidAmount(
+                FromDecStrErr::InvalidCharacter,
+            ))?;
+
+        let amount = U256::from_str_radix(hex, 16).map_err(|e| {
+            TokenTransferError::InvalidAmount(match e.kind() {
+                FromStrRadixErrKind::InvalidLength => FromDecStrErr::InvalidLength,
+                _ => FromDecStrErr::InvalidCharacter,
+            })
+        })?;
+        Ok(Self(amount))
+    }
 }
 
 impl AsRef<U256> for Amount {
@@ -63,3 +127,121 @@ impl From<u64> for Amount {
         Self(v.into())
     }
 }
+
+impl From<u128> for Amount {
+    fn from(v: u128) -> Self {
+        Self(v.into())
+    }
+}
+
+impl TryFrom<Amount> for u128 {
+    type Error = TokenTransferError;
+
+    fn try_from(amount: Amount) -> Result<Self, Self::Error> {
+        if amount.0 > U256::from(u128::MAX) {
+            return Err(TokenTransferError::AmountOverflow);
+        }
+        Ok(amount.0.low_u128())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn checked_mul_amount() {
+        let two = Amount::from(2u64);
+
+        assert_eq!(
+            Amount::from(21u64).checked_mul(two),
+            Some(Amount::from(42u64))
+        );
+        assert_eq!(Amount::from(U256::MAX).checked_mul(two), None);
+        assert_eq!(
+            Amount::from(U256::MAX / 2).checked_mul(two),
+            Some(Amount::from(U256::MAX - 1))
+        );
+    }
+
+    #[test]
+    fn checked_div_amount() {
+        assert_eq!(
+            Amount::from(42u64).checked_div(Amount::from(2u64)),
+            Some(Amount::from(21u64))
+        );
+        assert_eq!(Amount::from(42u64).checked_div(Amount::from(0u64)), None);
+    }
+
+    #[test]
+    fn amount_u128_conversion() {
+        let amount = Amount::from(u128::MAX);
+        assert_eq!(u128::try_from(amount).unwrap(), u128::MAX);
+
+        let overflowing = Amount::from(U256::from(u128::MAX) + U256::from(1u64));
+        assert!(matches!(
+            u128::try_from(overflowing),
+            Err(TokenTransferError::AmountOverflow)
+        ));
+    }
+
+    #[test]
+    fn amount_as_u256() {
+        let value = U256::from(u128::MAX) + U256::from(1u64);
+        let amount = Amount::from(value);
+
+        assert_eq!(amount.as_u256(), value);
+        assert!(u128::try_from(amount).is_err());
+        assert_eq!(
+            u128::try_from(Amount::from(U256::from(42u64))).unwrap(),
+            42u128
+        );
+    }
+
+    #[test]
+    fn zero_amount() {
+        assert!(Amount::from(0u64).is_zero());
+        assert!(!Amount::from(1u64).is_zero());
+    }
+
+    #[test]
+    fn parse_hex_amount() {
+        assert_eq!(Amount::from_hex_str("0xff").unwrap(), Amount::from(255u64));
+        assert_eq!(Amount::from_hex_str("0XFF").unwrap(), Amount::from(255u64));
+        assert!(matches!(
+            Amount::from_hex_str("0xzz"),
+            Err(TokenTransferError::InvalidAmount(_))
+        ));
+        assert!(matches!(
+            Amount::from_hex_str("0x"),
+            Err(TokenTransferError::InvalidAmount(_))
+        ));
+        assert!(matches!(
+            Amount::from_hex_str("ff"),
+            Err(TokenTransferError::InvalidAmount(_))
+        ));
+    }
+
+    #[test]
+    fn from_str_only_accepts_decimal() {
+        assert_eq!(Amount::from_str("255").unwrap(), Amount::from(255u64));
+        assert!(matches!(
+            Amount::from_str("0xff"),
+            Err(TokenTransferError::InvalidAmount(_))
+        ));
+        assert!(serde_json::from_str::<Amount>("\"0xff\"").is_err());
+    }
+
+    #[test]
+    fn serde_amount_as_string() {
+        let value = U256::from(u64::MAX) + U256::from(1u64);
+        let amount = Amount::from(value);
+
+        let json = serde_json::to_string(&amount).expect("serialization never fails");
+        assert_eq!(json, format!("\"{value}\""));
+
+        let deserialized: Amount = serde_json::from_str(&json).expect("valid amount json");
+        assert_eq!(deserialized, amount);
+    }
+}
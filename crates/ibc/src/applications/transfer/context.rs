@@ -11,7 +11,7 @@ use crate::applications::transfer::relay::refund_packet_token_execute;
 use crate::applications::transfer::relay::{
     on_recv_packet::process_recv_packet_execute, refund_packet_token_validate,
 };
-use crate::applications::transfer::{PrefixedCoin, PrefixedDenom, VERSION};
+use crate::applications::transfer::{Amount, PrefixedCoin, PrefixedDenom, VERSION};
 use crate::core::ics04_channel::acknowledgement::Acknowledgement;
 use crate::core::ics04_channel::acknowledgement::AcknowledgementStatus;
 use crate::core::ics04_channel::channel::{Counterparty, Order};
@@ -72,6 +72,43 @@ pub trait TokenTransferValidationContext: SendPacketValidationContext {
     fn denom_hash_string(&self, _denom: &PrefixedDenom) -> Option<String> {
         None
     }
+
+    /// Returns the amount of `denom` currently held in escrow for the given
+    /// port and channel combination, or `None` if the host does not track
+    /// escrow balances.
+    ///
+    /// Escrow accounting is opt-in. A host that enables it on a chain with
+    /// packets already in flight must first seed the current escrow balances
+    /// through `store_escrowed_amount`, otherwise refunds and unescrows of
+    /// those packets are rejected with `InsufficientEscrow`.
+    fn get_escrowed_amount(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _denom: &PrefixedDenom,
+    ) -> Result<Option<Amount>, TokenTransferError> {
+        Ok(None)
+    }
+
+    /// Validates that `coin` can be released from the escrow account of the
+    /// given port and channel to `to_account`. Fails if escrow balances are
+    /// tracked and more than the escrowed amount is requested.
+    fn unescrow_coins_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        to_account: &Self::AccountId,
+        coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        if let Some(escrowed) = self.get_escrowed_amount(port_id, channel_id, &coin.denom)? {
+            if escrowed.checked_sub(coin.amount).is_none() {
+                return Err(insufficient_escrow(port_id, channel_id, coin, escrowed));
+            }
+        }
+
+        let escrow_address = self.get_escrow_account(port_id, channel_id)?;
+        self.send_coins_validate(&escrow_address, to_account, coin)
+    }
 }
 
 /// Methods required in token transfer execution, to be implemented by the host
@@ -99,6 +136,90 @@ pub trait TokenTransferExecutionContext:
         account: &Self::AccountId,
         coin: &PrefixedCoin,
     ) -> Result<(), TokenTransferError>;
+
+    /// Stores the amount of `denom` held in escrow for the given port and
+    /// channel combination. Only called for escrow balances that
+    /// `get_escrowed_amount` reports as tracked.
+    fn store_escrowed_amount(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _denom: &PrefixedDenom,
+        _amount: Amount,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    /// Moves `coin` from `from_account` into the escrow account of the given
+    /// port and channel, and records the increased escrow balance
+    fn escrow_coins(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        from_account: &Self::AccountId,
+        coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        let new_escrowed = match self.get_escrowed_amount(port_id, channel_id, &coin.denom)? {
+            Some(escrowed) => Some(
+                escrowed
+                    .checked_add(coin.amount)
+                    .ok_or(TokenTransferError::AmountOverflow)?,
+            ),
+            None => None,
+        };
+
+        let escrow_address = self.get_escrow_account(port_id, channel_id)?;
+        self.send_coins_execute(from_account, &escrow_address, coin)?;
+
+        match new_escrowed {
+            Some(amount) => self.store_escrowed_amount(port_id, channel_id, &coin.denom, amount),
+            None => Ok(()),
+        }
+    }
+
+    /// Releases `coin` from the escrow account of the given port and channel
+    /// to `to_account`, and records the decreased escrow balance. Fails if
+    /// escrow balances are tracked and more than the escrowed amount is
+    /// requested.
+    fn unescrow_coins(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        to_account: &Self::AccountId,
+        coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        let new_escrowed = match self.get_escrowed_amount(port_id, channel_id, &coin.denom)? {
+            Some(escrowed) => Some(
+                escrowed
+                    .checked_sub(coin.amount)
+                    .ok_or_else(|| insufficient_escrow(port_id, channel_id, coin, escrowed))?,
+            ),
+            None => None,
+        };
+
+        let escrow_address = self.get_escrow_account(port_id, channel_id)?;
+        self.send_coins_execute(&escrow_address, to_account, coin)?;
+
+        match new_escrowed {
+            Some(amount) => self.store_escrowed_amount(port_id, channel_id, &coin.denom, amount),
+            None => Ok(()),
+        }
+    }
+}
+
+fn insufficient_escrow(
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    coin: &PrefixedCoin,
+    escrowed: Amount,
+) -> TokenTransferError {
+    TokenTransferError::InsufficientEscrow {
+        port_id: port_id.clone(),
+        channel_id: channel_id.clone(),
+        denom: coin.denom.clone(),
+        escrowed,
+        requested: coin.amount,
+    }
 }
 
 // https://github.com/cosmos/cosmos-sdk/blob/master/docs/architecture/adr-028-public-key-addresses.md
@@ -420,13 +541,16 @@ pub fn on_timeout_packet_execute(
 #[cfg(test)]
 pub(crate) mod test {
     use super::*;
+    use primitive_types::U256;
     use subtle_encoding::bech32;
 
     use crate::applications::transfer::context::cosmos_adr028_escrow_address;
     use crate::core::ics04_channel::channel::{Counterparty, Order};
+    use crate::core::ics04_channel::packet::test_utils::get_dummy_raw_packet;
     use crate::core::ics04_channel::Version;
     use crate::core::ics24_host::identifier::{ChannelId, ConnectionId, PortId};
     use crate::mock::context::MockContext;
+    use crate::test_utils::get_dummy_bech32_account;
 
     fn get_defaults() -> (
         MockContext,
@@ -453,6 +577,25 @@ pub(crate) mod test {
         )
     }
 
+    /// Returns a dummy packet between the default ports and channels carrying `data`
+    fn get_dummy_transfer_packet(data: Vec<u8>) -> Packet {
+        let mut packet: Packet = get_dummy_raw_packet(10, 0).try_into().unwrap();
+        packet.data = data;
+        packet
+    }
+
+    fn ack_status(ack: &Acknowledgement) -> AcknowledgementStatus {
+        serde_json::from_slice(ack.as_bytes()).unwrap()
+    }
+
+    /// Returns dummy packet data transferring a voucher that did not originate
+    /// from the sending chain, so that refunds mint it back to the sender
+    fn get_dummy_voucher_packet_data() -> PacketData {
+        let mut data = PacketData::new_dummy();
+        data.token.denom = "transfer/channel-1/uatom".parse().unwrap();
+        data
+    }
+
     #[test]
     fn test_cosmos_escrow_address() {
         fn assert_eq_escrow_address(port_id: &str, channel_id: &str, address: &str) {
@@ -483,6 +626,110 @@ pub(crate) mod test {
         );
     }
 
+    #[test]
+    fn test_escrow_then_over_unescrow() {
+        let (mut ctx, _, _, port_id, channel_id, _) = get_defaults();
+
+        let account: Signer = get_dummy_bech32_account().into();
+        let coin = |amount: u64| PrefixedCoin {
+            denom: "uatom".parse().unwrap(),
+            amount: amount.into(),
+        };
+
+        ctx.escrow_coins(&port_id, &channel_id, &account, &coin(100))
+            .unwrap();
+        assert_eq!(
+            ctx.get_escrowed_amount(&port_id, &channel_id, &coin(0).denom)
+                .unwrap(),
+            Some(100u64.into())
+        );
+
+        ctx.unescrow_coins(&port_id, &channel_id, &account, &coin(40))
+            .unwrap();
+        assert_eq!(
+            ctx.get_escrowed_amount(&port_id, &channel_id, &coin(0).denom)
+                .unwrap(),
+            Some(60u64.into())
+        );
+
+        ctx.unescrow_coins_validate(&port_id, &channel_id, &account, &coin(60))
+            .unwrap();
+        let res = ctx.unescrow_coins_validate(&port_id, &channel_id, &account, &coin(61));
+        assert!(matches!(
+            res,
+            Err(TokenTransferError::InsufficientEscrow { .. })
+        ));
+
+        let res = ctx.unescrow_coins(&port_id, &channel_id, &account, &coin(61));
+        assert!(matches!(
+            res,
+            Err(TokenTransferError::InsufficientEscrow { .. })
+        ));
+        assert_eq!(
+            ctx.get_escrowed_amount(&port_id, &channel_id, &coin(0).denom)
+                .unwrap(),
+            Some(60u64.into())
+        );
+    }
+
+    #[test]
+    fn test_escrow_overflow() {
+        let (mut ctx, _, _, port_id, channel_id, _) = get_defaults();
+
+        let account: Signer = get_dummy_bech32_account().into();
+        let coin = |amount: U256| PrefixedCoin {
+            denom: "uatom".parse().unwrap(),
+            amount: amount.into(),
+        };
+
+        ctx.escrow_coins(&port_id, &channel_id, &account, &coin(U256::MAX - 1))
+            .unwrap();
+
+        let res = ctx.escrow_coins(&port_id, &channel_id, &account, &coin(U256::from(2)));
+        assert!(matches!(res, Err(TokenTransferError::AmountOverflow)));
+        assert_eq!(
+            ctx.get_escrowed_amount(&port_id, &channel_id, &coin(U256::zero()).denom)
+                .unwrap(),
+            Some((U256::MAX - 1).into())
+        );
+
+        ctx.escrow_coins(&port_id, &channel_id, &account, &coin(U256::one()))
+            .unwrap();
+        assert_eq!(
+            ctx.get_escrowed_amount(&port_id, &channel_id, &coin(U256::zero()).denom)
+                .unwrap(),
+            Some(U256::MAX.into())
+        );
+    }
+
+    #[test]
+    fn test_mint_then_over_burn() {
+        let (mut ctx, _, _, _, _, _) = get_defaults();
+
+        let account: Signer = get_dummy_bech32_account().into();
+        let coin = |amount: u64| PrefixedCoin {
+            denom: "transfer/channel-1/uatom".parse().unwrap(),
+            amount: amount.into(),
+        };
+
+        ctx.mint_coins_validate(&account, &coin(100)).unwrap();
+        ctx.mint_coins_execute(&account, &coin(100)).unwrap();
+
+        ctx.burn_coins_validate(&account, &coin(40)).unwrap();
+        ctx.burn_coins_execute(&account, &coin(40)).unwrap();
+
+        assert!(matches!(
+            ctx.burn_coins_validate(&account, &coin(61)),
+            Err(TokenTransferError::InsufficientSupply { .. })
+        ));
+        assert!(matches!(
+            ctx.burn_coins_execute(&account, &coin(61)),
+            Err(TokenTransferError::InsufficientSupply { .. })
+        ));
+
+        ctx.burn_coins_execute(&account, &coin(60)).unwrap();
+    }
+
     /// If the relayer passed "", indicating that it wants us to return the versions we support.
     /// We currently only support ics20
     #[test]
@@ -584,4 +831,123 @@ pub(crate) mod test {
 
         assert!(res.is_err());
     }
+
+    #[test]
+    fn test_on_recv_packet_mints_vouchers() {
+        let (mut ctx, _, _, _, _, _) = get_defaults();
+
+        let data = PacketData::new_dummy();
+        let packet = get_dummy_transfer_packet(serde_json::to_vec(&data).unwrap());
+
+        let (extras, ack) = on_recv_packet_execute(&mut ctx, &packet);
+        assert!(ack_status(&ack).is_successful());
+        assert!(!extras.events.is_empty());
+
+        // "uatom" originates from the sending chain, so a voucher is minted
+        let voucher = PrefixedCoin {
+            denom: format!(
+                "{}/{}/{}",
+                packet.port_id_on_b, packet.chan_id_on_b, data.token.denom
+            )
+            .parse()
+            .unwrap(),
+            amount: data.token.amount,
+        };
+        ctx.burn_coins_validate(&data.receiver, &voucher).unwrap();
+    }
+
+    #[test]
+    fn test_on_recv_packet_malformed_data() {
+        let (mut ctx, _, _, _, _, _) = get_defaults();
+
+        let packet = get_dummy_transfer_packet(vec![0]);
+
+        let (_, ack) = on_recv_packet_execute(&mut ctx, &packet);
+        let status = ack_status(&ack);
+        assert!(!status.is_successful());
+        assert_eq!(
+            status,
+            AcknowledgementStatus::error(TokenTransferError::PacketDataDeserialization.into())
+        );
+    }
+
+    #[test]
+    fn test_on_recv_packet_empty_receiver() {
+        let (mut ctx, _, _, _, _, _) = get_defaults();
+
+        let mut data = PacketData::new_dummy();
+        data.receiver = String::new().into();
+        let packet = get_dummy_transfer_packet(serde_json::to_vec(&data).unwrap());
+
+        let (_, ack) = on_recv_packet_execute(&mut ctx, &packet);
+        assert_eq!(
+            ack_status(&ack),
+            AcknowledgementStatus::error(
+                TokenTransferError::InvalidReceiverAddress {
+                    receiver: String::new()
+                }
+                .into()
+            )
+        );
+    }
+
+    #[test]
+    fn test_on_acknowledgement_packet_success_does_not_refund() {
+        let (mut ctx, _, _, _, _, _) = get_defaults();
+
+        let data = get_dummy_voucher_packet_data();
+        let packet = get_dummy_transfer_packet(serde_json::to_vec(&data).unwrap());
+        let ack: Acknowledgement = AcknowledgementStatus::success(ack_success_b64()).into();
+        let relayer: Signer = get_dummy_bech32_account().into();
+
+        on_acknowledgement_packet_validate(&ctx, &packet, &ack, &relayer).unwrap();
+        let (_, res) = on_acknowledgement_packet_execute(&mut ctx, &packet, &ack, &relayer);
+        res.unwrap();
+
+        // nothing was minted back to the sender
+        assert!(matches!(
+            ctx.burn_coins_validate(&data.sender, &data.token),
+            Err(TokenTransferError::InsufficientSupply { .. })
+        ));
+    }
+
+    #[test]
+    fn test_on_acknowledgement_packet_error_refunds() {
+        let (mut ctx, _, _, _, _, _) = get_defaults();
+
+        let data = get_dummy_voucher_packet_data();
+        let packet = get_dummy_transfer_packet(serde_json::to_vec(&data).unwrap());
+        let ack: Acknowledgement =
+            AcknowledgementStatus::error(TokenTransferError::InvalidToken.into()).into();
+        let relayer: Signer = get_dummy_bech32_account().into();
+
+        on_acknowledgement_packet_validate(&ctx, &packet, &ack, &relayer).unwrap();
+        let (_, res) = on_acknowledgement_packet_execute(&mut ctx, &packet, &ack, &relayer);
+        res.unwrap();
+
+        // the voucher was minted back to the sender
+        ctx.burn_coins_validate(&data.sender, &data.token).unwrap();
+    }
+
+    #[test]
+    fn test_on_timeout_packet_refunds() {
+        let (mut ctx, _, _, _, _, _) = get_defaults();
+
+        let data = get_dummy_voucher_packet_data();
+        let packet = get_dummy_transfer_packet(serde_json::to_vec(&data).unwrap());
+        let relayer: Signer = get_dummy_bech32_account().into();
+
+        // the sender holds the vouchers, which are burnt when sending them
+        ctx.mint_coins_execute(&data.sender, &data.token).unwrap();
+        ctx.burn_coins_execute(&data.sender, &data.token).unwrap();
+        assert!(ctx.burn_coins_validate(&data.sender, &data.token).is_err());
+
+        on_timeout_packet_validate(&ctx, &packet, &relayer).unwrap();
+        let (extras, res) = on_timeout_packet_execute(&mut ctx, &packet, &relayer);
+        res.unwrap();
+        assert_eq!(extras.events.len(), 1);
+
+        // the vouchers were minted back to the sender
+        ctx.burn_coins_validate(&data.sender, &data.token).unwrap();
+    }
 }
@@ -74,6 +74,14 @@ impl TracePrefix {
             channel_id,
         }
     }
+
+    pub fn port_id(&self) -> &PortId {
+        &self.port_id
+    }
+
+    pub fn channel_id(&self) -> &ChannelId {
+        &self.channel_id
+    }
 }
 
 impl Display for TracePrefix {
@@ -119,6 +127,17 @@ impl TracePath {
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+
+    /// Returns the number of hops in the path.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns an iterator over the hops of the path, starting from the most
+    /// recently added prefix, i.e. in the order they appear in the string form.
+    pub fn iter(&self) -> impl Iterator<Item = &TracePrefix> {
+        self.0.iter().rev()
+    }
 }
 
 impl<'a> TryFrom<Vec<&'a str>> for TracePath {
@@ -202,14 +221,28 @@ pub struct PrefixedDenom {
 }
 
 impl PrefixedDenom {
-    /// Removes the specified prefix from the trace path if there is a match, otherwise does nothing.
-    pub fn remove_trace_prefix(&mut self, prefix: &TracePrefix) {
-        self.trace_path.remove_prefix(prefix)
+    /// Removes the `{port_id}/{channel_id}` prefix from the trace path, returning an error
+    /// if the trace path does not start with it.
+    pub fn remove_trace_prefix(
+        &mut self,
+        port_id: PortId,
+        channel_id: ChannelId,
+    ) -> Result<(), TokenTransferError> {
+        let prefix = TracePrefix::new(port_id, channel_id);
+        if !self.trace_path.starts_with(&prefix) {
+            return Err(TokenTransferError::TracePrefixMismatch {
+                prefix,
+                denom: self.clone(),
+            });
+        }
+        self.trace_path.remove_prefix(&prefix);
+        Ok(())
     }
 
-    /// Adds the specified prefix to the trace path.
-    pub fn add_trace_prefix(&mut self, prefix: TracePrefix) {
-        self.trace_path.add_prefix(prefix)
+    /// Adds the `{port_id}/{channel_id}` prefix to the trace path.
+    pub fn add_trace_prefix(&mut self, port_id: PortId, channel_id: ChannelId) {
+        self.trace_path
+            .add_prefix(TracePrefix::new(port_id, channel_id))
     }
 }
 
@@ -405,6 +438,57 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_chain_source() -> Result<(), TokenTransferError> {
+        let port_id = PortId::transfer();
+        let channel_id: ChannelId = "channel-0".parse()?;
+
+        // The native denom has no trace, so the sender chain is the source.
+        let native = PrefixedDenom::from_str("uatom")?;
+        assert!(is_sender_chain_source(
+            port_id.clone(),
+            channel_id.clone(),
+            &native
+        ));
+        assert!(!is_receiver_chain_source(
+            port_id.clone(),
+            channel_id.clone(),
+            &native
+        ));
+
+        // A single-hop voucher returning through the channel it came from.
+        let single_hop = PrefixedDenom::from_str("transfer/channel-0/uatom")?;
+        assert!(is_receiver_chain_source(
+            port_id.clone(),
+            channel_id.clone(),
+            &single_hop
+        ));
+        assert!(!is_sender_chain_source(
+            port_id.clone(),
+            channel_id.clone(),
+            &single_hop
+        ));
+
+        // A multi-hop voucher returning through the channel of its latest hop.
+        let multi_hop = PrefixedDenom::from_str("transfer/channel-0/transfer/channel-1/uatom")?;
+        assert!(is_receiver_chain_source(
+            port_id.clone(),
+            channel_id.clone(),
+            &multi_hop
+        ));
+
+        // A multi-hop voucher sent through a channel other than its latest hop.
+        let multi_hop = PrefixedDenom::from_str("transfer/channel-1/transfer/channel-0/uatom")?;
+        assert!(is_sender_chain_source(
+            port_id.clone(),
+            channel_id.clone(),
+            &multi_hop
+        ));
+        assert!(!is_receiver_chain_source(port_id, channel_id, &multi_hop));
+
+        Ok(())
+    }
+
     #[test]
     fn test_trace_path() -> Result<(), TokenTransferError> {
         assert!(TracePath::from_str("").is_ok(), "empty trace path");
@@ -444,4 +528,42 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_trace_path_iter() -> Result<(), TokenTransferError> {
+        let trace_path = TracePath::from_str("transfer/channel-0/transfer/channel-1")?;
+        assert_eq!(trace_path.len(), 2);
+        assert!(!trace_path.is_empty());
+
+        let hops: Vec<String> = trace_path
+            .iter()
+            .map(|prefix| format!("{}/{}", prefix.port_id(), prefix.channel_id()))
+            .collect();
+        assert_eq!(hops, vec!["transfer/channel-0", "transfer/channel-1"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_remove_trace_prefix() -> Result<(), TokenTransferError> {
+        let port_id: PortId = "transfer".parse().unwrap();
+        let channel_id: ChannelId = "channel-0".parse().unwrap();
+        let mut denom = PrefixedDenom::from_str("transfer/channel-1/uatom")?;
+
+        denom.add_trace_prefix(port_id.clone(), channel_id.clone());
+        assert_eq!(
+            denom,
+            PrefixedDenom::from_str("transfer/channel-0/transfer/channel-1/uatom")?
+        );
+
+        assert!(matches!(
+            denom.remove_trace_prefix(port_id.clone(), "channel-1".parse().unwrap()),
+            Err(TokenTransferError::TracePrefixMismatch { .. })
+        ));
+
+        denom.remove_trace_prefix(port_id, channel_id)?;
+        assert_eq!(denom, PrefixedDenom::from_str("transfer/channel-1/uatom")?);
+
+        Ok(())
+    }
 }
@@ -6,6 +6,7 @@ use displaydoc::Display;
 use ibc_proto::protobuf::Error as TendermintProtoError;
 use uint::FromDecStrErr;
 
+use crate::applications::transfer::{Amount, PrefixedDenom, TracePrefix};
 use crate::core::ics04_channel::acknowledgement::StatusValue;
 use crate::core::ics04_channel::channel::Order;
 use crate::core::ics24_host::identifier::{ChannelId, IdentifierError, PortId};
@@ -37,10 +38,35 @@ pub enum TokenTransferError {
     },
     /// trace length must be even but got: `{len}`
     InvalidTraceLength { len: usize },
+    /// trace prefix `{prefix}` does not match the denom `{denom}`
+    TracePrefixMismatch {
+        prefix: TracePrefix,
+        denom: PrefixedDenom,
+    },
     /// invalid amount error: `{0}`
     InvalidAmount(FromDecStrErr),
+    /// amount overflows the target integer type
+    AmountOverflow,
+    /// insufficient escrow of `{denom}` on port `{port_id}` and channel `{channel_id}`: escrowed `{escrowed}`, requested `{requested}`
+    InsufficientEscrow {
+        port_id: PortId,
+        channel_id: ChannelId,
+        denom: PrefixedDenom,
+        escrowed: Amount,
+        requested: Amount,
+    },
+    /// insufficient supply of `{denom}`: minted `{supply}`, requested `{requested}`
+    InsufficientSupply {
+        denom: PrefixedDenom,
+        supply: Amount,
+        requested: Amount,
+    },
     /// invalid token
     InvalidToken,
+    /// transfer amount must be non-zero
+    ZeroAmount,
+    /// receiver address must not be empty
+    EmptyReceiver,
     /// expected `{expect_order}` channel, got `{got_order}`
     ChannelNotUnordered {
         expect_order: Order,
@@ -58,6 +84,8 @@ pub enum TokenTransferError {
     SendDisabled { reason: String },
     /// failed to parse as AccountId
     ParseAccountFailure,
+    /// invalid receiver address: `{receiver}`
+    InvalidReceiverAddress { receiver: String },
     /// invalid port: `{port_id}`, expected `{exp_port_id}`
     InvalidPort {
         port_id: PortId,
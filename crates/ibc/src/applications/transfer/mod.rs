@@ -34,8 +34,25 @@ pub const VERSION: &str = "ics20-1";
 pub const ACK_SUCCESS_B64: &str = "AQ==";
 
 use crate::core::ics04_channel::acknowledgement::StatusValue;
+use crate::core::router::ModuleId;
+use crate::prelude::*;
+
+/// Returns the module identifier for the ICS20 application.
+pub fn module_id() -> ModuleId {
+    ModuleId::new(MODULE_ID_STR.to_string())
+}
 
 /// Returns a successful acknowledgement status for the token transfer application.
 pub fn ack_success_b64() -> StatusValue {
     StatusValue::new(ACK_SUCCESS_B64).expect("ack status value is never supposed to be empty")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_module_id() {
+        assert_eq!(module_id(), ModuleId::new(MODULE_ID_STR.to_string()));
+    }
+}
@@ -8,6 +8,7 @@ use ibc_proto::protobuf::Protobuf;
 
 use crate::applications::transfer::error::TokenTransferError;
 use crate::applications::transfer::packet::PacketData;
+use crate::applications::transfer::PrefixedCoin;
 use crate::core::ics04_channel::error::PacketError;
 use crate::core::ics04_channel::timeout::TimeoutHeight;
 use crate::core::ics24_host::identifier::{ChannelId, PortId};
@@ -70,15 +71,24 @@ impl TryFrom<RawMsgTransfer> for MsgTransfer {
             return Err(PacketError::MissingTimeout).map_err(ContextError::from)?;
         }
 
+        let token: PrefixedCoin = raw_msg
+            .token
+            .ok_or(TokenTransferError::InvalidToken)?
+            .try_into()
+            .map_err(|_| TokenTransferError::InvalidToken)?;
+        if token.amount.is_zero() {
+            return Err(TokenTransferError::ZeroAmount);
+        }
+
+        if raw_msg.receiver.is_empty() {
+            return Err(TokenTransferError::EmptyReceiver);
+        }
+
         Ok(MsgTransfer {
             port_id_on_a: raw_msg.source_port.parse()?,
             chan_id_on_a: raw_msg.source_channel.parse()?,
             packet_data: PacketData {
-                token: raw_msg
-                    .token
-                    .ok_or(TokenTransferError::InvalidToken)?
-                    .try_into()
-                    .map_err(|_| TokenTransferError::InvalidToken)?,
+                token,
                 sender: raw_msg.sender.into(),
                 receiver: raw_msg.receiver.into(),
                 memo: raw_msg.memo.into(),
@@ -168,3 +178,51 @@ pub mod test_util {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::ics02_client::height::Height;
+
+    fn dummy_raw_msg_transfer() -> RawMsgTransfer {
+        MsgTransfer::new_dummy(Height::new(0, 10).unwrap().into(), None).into()
+    }
+
+    #[test]
+    fn msg_transfer_proto_round_trip() {
+        let msg = MsgTransfer::new_dummy(Height::new(0, 10).unwrap().into(), None);
+        let raw = RawMsgTransfer::from(msg.clone());
+        assert_eq!(MsgTransfer::try_from(raw).unwrap(), msg);
+
+        let decoded = MsgTransfer::decode_vec(&msg.clone().encode_vec()).unwrap();
+        assert_eq!(decoded, msg);
+    }
+
+    #[test]
+    fn msg_transfer_validation() {
+        assert!(MsgTransfer::try_from(dummy_raw_msg_transfer()).is_ok());
+
+        let mut raw = dummy_raw_msg_transfer();
+        if let Some(token) = raw.token.as_mut() {
+            token.amount = "0".to_string();
+        }
+        assert!(matches!(
+            MsgTransfer::try_from(raw),
+            Err(TokenTransferError::ZeroAmount)
+        ));
+
+        let raw = RawMsgTransfer {
+            receiver: String::new(),
+            ..dummy_raw_msg_transfer()
+        };
+        assert!(matches!(
+            MsgTransfer::try_from(raw),
+            Err(TokenTransferError::EmptyReceiver)
+        ));
+
+        let raw: RawMsgTransfer =
+            MsgTransfer::new_dummy(TimeoutHeight::no_timeout(), Some(Timestamp::none())).into();
+        assert!(MsgTransfer::try_from(raw).is_err());
+    }
+}
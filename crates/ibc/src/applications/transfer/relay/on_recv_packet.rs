@@ -1,8 +1,8 @@
 use crate::applications::transfer::context::TokenTransferExecutionContext;
 use crate::applications::transfer::error::TokenTransferError;
 use crate::applications::transfer::events::DenomTraceEvent;
+use crate::applications::transfer::is_receiver_chain_source;
 use crate::applications::transfer::packet::PacketData;
-use crate::applications::transfer::{is_receiver_chain_source, TracePrefix};
 use crate::core::ics04_channel::packet::Packet;
 use crate::core::router::ModuleExtras;
 use crate::prelude::*;
@@ -22,12 +22,24 @@ pub fn process_recv_packet_execute<Ctx: TokenTransferExecutionContext>(
         .can_receive_coins()
         .map_err(|err| (ModuleExtras::empty(), err))?;
 
-    let receiver_account = data.receiver.clone().try_into().map_err(|_| {
+    let invalid_receiver = || {
         (
             ModuleExtras::empty(),
-            TokenTransferError::ParseAccountFailure,
+            TokenTransferError::InvalidReceiverAddress {
+                receiver: data.receiver.to_string(),
+            },
         )
-    })?;
+    };
+
+    if data.receiver.as_ref().is_empty() {
+        return Err(invalid_receiver());
+    }
+
+    let receiver_account = data
+        .receiver
+        .clone()
+        .try_into()
+        .map_err(|_| invalid_receiver())?;
 
     let extras = if is_receiver_chain_source(
         packet.port_id_on_a.clone(),
@@ -35,17 +47,14 @@ pub fn process_recv_packet_execute<Ctx: TokenTransferExecutionContext>(
         &data.token.denom,
     ) {
         // sender chain is not the source, unescrow tokens
-        let prefix = TracePrefix::new(packet.port_id_on_a.clone(), packet.chan_id_on_a.clone());
         let coin = {
             let mut c = data.token;
-            c.denom.remove_trace_prefix(&prefix);
+            c.denom
+                .remove_trace_prefix(packet.port_id_on_a.clone(), packet.chan_id_on_a.clone())
+                .map_err(|token_err| (ModuleExtras::empty(), token_err))?;
             c
         };
 
-        let escrow_address = ctx_b
-            .get_escrow_account(&packet.port_id_on_b, &packet.chan_id_on_b)
-            .map_err(|token_err| (ModuleExtras::empty(), token_err))?;
-
         // Note: it is correct to do the validation here because `recv_packet()`
         // works slightly differently. We do not have a
         // `on_recv_packet_validate()` callback because regardless of whether or
@@ -57,20 +66,30 @@ pub fn process_recv_packet_execute<Ctx: TokenTransferExecutionContext>(
         // gets relayed back to the sender so that the escrowed tokens
         // can be refunded.
         ctx_b
-            .send_coins_validate(&escrow_address, &receiver_account, &coin)
+            .unescrow_coins_validate(
+                &packet.port_id_on_b,
+                &packet.chan_id_on_b,
+                &receiver_account,
+                &coin,
+            )
             .map_err(|token_err| (ModuleExtras::empty(), token_err))?;
 
         ctx_b
-            .send_coins_execute(&escrow_address, &receiver_account, &coin)
+            .unescrow_coins(
+                &packet.port_id_on_b,
+                &packet.chan_id_on_b,
+                &receiver_account,
+                &coin,
+            )
             .map_err(|token_err| (ModuleExtras::empty(), token_err))?;
 
         ModuleExtras::empty()
     } else {
         // sender chain is the source, mint vouchers
-        let prefix = TracePrefix::new(packet.port_id_on_b.clone(), packet.chan_id_on_b.clone());
         let coin = {
             let mut c = data.token;
-            c.denom.add_trace_prefix(prefix);
+            c.denom
+                .add_trace_prefix(packet.port_id_on_b.clone(), packet.chan_id_on_b.clone());
             c
         };
 
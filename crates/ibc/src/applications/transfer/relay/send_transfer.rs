@@ -123,8 +123,7 @@ where
         msg.chan_id_on_a.clone(),
         &token.denom,
     ) {
-        let escrow_address = ctx_a.get_escrow_account(&msg.port_id_on_a, &msg.chan_id_on_a)?;
-        ctx_a.send_coins_execute(&sender, &escrow_address, token)?;
+        ctx_a.escrow_coins(&msg.port_id_on_a, &msg.chan_id_on_a, &sender, token)?;
     } else {
         ctx_a.burn_coins_execute(&sender, token)?;
     }
@@ -169,3 +168,91 @@ where
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::applications::transfer::packet::PacketData;
+    use crate::core::events::IbcEvent;
+    use crate::core::ics02_client::height::Height;
+    use crate::core::ics03_connection::connection::{
+        ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+    };
+    use crate::core::ics03_connection::version::get_compatible_versions;
+    use crate::core::ics04_channel::channel::{ChannelEnd, Counterparty, Order, State};
+    use crate::core::ics04_channel::Version;
+    use crate::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+    use crate::core::timestamp::ZERO_DURATION;
+    use crate::core::ValidationContext;
+    use crate::mock::context::MockContext;
+
+    #[test]
+    fn send_transfer_native_token() {
+        let chan_end_on_a = ChannelEnd::new(
+            State::Open,
+            Order::Unordered,
+            Counterparty::new(PortId::transfer(), Some(ChannelId::new(1))),
+            vec![ConnectionId::default()],
+            Version::new("ics20-1".to_string()),
+        )
+        .unwrap();
+
+        let conn_end_on_a = ConnectionEnd::new(
+            ConnectionState::Open,
+            ClientId::default(),
+            ConnectionCounterparty::new(
+                ClientId::default(),
+                Some(ConnectionId::default()),
+                Default::default(),
+            ),
+            get_compatible_versions(),
+            ZERO_DURATION,
+        )
+        .unwrap();
+
+        let mut ctx = MockContext::default()
+            .with_client(&ClientId::default(), Height::new(0, 5).unwrap())
+            .with_connection(ConnectionId::default(), conn_end_on_a)
+            .with_channel(PortId::default(), ChannelId::default(), chan_end_on_a)
+            .with_send_sequence(PortId::default(), ChannelId::default(), 1.into());
+
+        // "uatom" carries no trace, so the tokens are escrowed on the sending chain
+        let msg = MsgTransfer::new_dummy(Height::new(0, 10).unwrap().into(), None);
+        assert!(is_sender_chain_source(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            &msg.packet_data.token.denom,
+        ));
+
+        send_transfer(&mut ctx, msg.clone()).unwrap();
+
+        assert_eq!(
+            ctx.get_escrowed_amount(
+                &msg.port_id_on_a,
+                &msg.chan_id_on_a,
+                &msg.packet_data.token.denom
+            )
+            .unwrap(),
+            Some(msg.packet_data.token.amount)
+        );
+
+        let send_packet_event = ctx
+            .events
+            .iter()
+            .find_map(|event| match event {
+                IbcEvent::SendPacket(e) => Some(e),
+                _ => None,
+            })
+            .expect("a send packet event must be emitted");
+        let packet_data: PacketData =
+            serde_json::from_slice(send_packet_event.packet_data()).unwrap();
+        assert_eq!(packet_data, msg.packet_data);
+
+        let seq_send_path_on_a = SeqSendPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+        assert_eq!(
+            ValidationContext::get_next_sequence_send(&ctx, &seq_send_path_on_a).unwrap(),
+            2.into()
+        );
+    }
+}
@@ -42,7 +42,7 @@ use crate::core::ics23_commitment::specs::ProofSpecs;
 use crate::core::ics24_host::identifier::{ChainId, ClientId};
 use crate::core::ics24_host::path::Path;
 use crate::core::ics24_host::path::{ClientConsensusStatePath, ClientStatePath, UpgradeClientPath};
-use crate::core::timestamp::ZERO_DURATION;
+use crate::core::timestamp::{Timestamp, ZERO_DURATION};
 use crate::Height;
 
 use super::trust_threshold::TrustThreshold;
@@ -147,6 +147,44 @@ impl ClientState {
         }
     }
 
+    /// Deletes the consensus states of the client `client_id` that were
+    /// stored more than the trusting period before `host_timestamp`, and
+    /// returns their heights. `update_state` calls it with the inputs of
+    /// [`ClientExecutionContext::consensus_states_for_pruning`] when the host
+    /// enables pruning with [`ClientExecutionContext::is_pruning_enabled`].
+    ///
+    /// `update_times` lists the heights of the stored consensus states in
+    /// ascending order, with the host time at which each was stored. The
+    /// consensus state at the latest height of the client is never pruned.
+    pub fn prune_expired_consensus_states<E>(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        host_timestamp: &Timestamp,
+        update_times: Vec<(Height, Timestamp)>,
+    ) -> Result<Vec<Height>, ClientError>
+    where
+        E: ClientExecutionContext,
+    {
+        let mut pruned_heights = Vec::new();
+        for (height, update_time) in update_times {
+            if height >= self.latest_height {
+                break;
+            }
+
+            let is_expired = host_timestamp
+                .duration_since(&update_time)
+                .map_or(false, |elapsed| self.expired(elapsed));
+
+            if is_expired {
+                ctx.delete_consensus_state(ClientConsensusStatePath::new(client_id, &height))?;
+                pruned_heights.push(height);
+            }
+        }
+
+        Ok(pruned_heights)
+    }
+
     pub fn validate(&self) -> Result<(), Error> {
         self.chain_id.validate_length(3, MaxChainIdLen)?;
 
@@ -456,7 +494,7 @@ where
             }
             UpdateKind::SubmitMisbehaviour => {
                 let misbehaviour = TmMisbehaviour::try_from(client_message)?;
-                self.check_for_misbehaviour_misbehavior(&misbehaviour)
+                self.detect_misbehaviour(&misbehaviour)
             }
         }
     }
@@ -506,6 +544,11 @@ where
             //
             // Do nothing.
         } else {
+            if ctx.is_pruning_enabled() {
+                let (host_timestamp, update_times) = ctx.consensus_states_for_pruning(client_id)?;
+                self.prune_expired_consensus_states(ctx, client_id, &host_timestamp, update_times)?;
+            }
+
             let new_consensus_state = TmConsensusState::from(header.clone());
             let new_client_state = self.clone().with_header(header)?;
 
@@ -767,7 +810,10 @@ mod tests {
     use crate::clients::ics07_tendermint::error::Error;
     use crate::core::ics23_commitment::specs::ProofSpecs;
     use crate::core::ics24_host::identifier::ChainId;
-    use crate::core::timestamp::ZERO_DURATION;
+    use crate::core::timestamp::{Timestamp, ZERO_DURATION};
+    use crate::core::{ExecutionContext, ValidationContext};
+    use crate::mock::context::MockContext;
+    use crate::mock::host::HostBlock;
 
     #[derive(Clone, Debug, PartialEq)]
     struct ClientStateParams {
@@ -1064,6 +1110,145 @@ mod tests {
             _ => panic!("Expected to fail with FrozenHeightNotAllowed error"),
         }
     }
+
+    #[test]
+    fn detect_misbehaviour() {
+        let chain_id = ChainId::new("mockgaiaB", 1).unwrap();
+        let client_id = ClientId::new(tm_client_type(), 0).unwrap();
+        let trusted_height = Height::new(1, 20).unwrap();
+        let now = Timestamp::now();
+        let later = (now + Duration::from_secs(10)).unwrap();
+
+        let header = |height: u64, timestamp: Timestamp| -> TmHeader {
+            let mut block = HostBlock::generate_tm_block(chain_id.clone(), height, timestamp);
+            block.trusted_height = trusted_height;
+            block.into()
+        };
+        let detect = |header1: TmHeader, header2: TmHeader| {
+            let client_state =
+                ClientState::new_dummy_from_header(header1.signed_header.header.clone());
+            client_state
+                .detect_misbehaviour(&TmMisbehaviour::new(client_id.clone(), header1, header2))
+                .unwrap()
+        };
+
+        // The same header submitted twice is not misbehaviour
+        assert!(!detect(header(21, now), header(21, now)));
+
+        // Two different headers at the same height are misbehaviour
+        assert!(detect(header(21, now), header(21, later)));
+
+        // A higher header that is not later than a lower one violates monotonic time
+        assert!(detect(header(22, now), header(21, later)));
+
+        // A higher header that is strictly later than a lower one is not misbehaviour
+        assert!(!detect(header(22, later), header(21, now)));
+    }
+
+    /// Stores consensus states of the client `client_id` at the latest height
+    /// of `client_state` and the two heights below it, the lowest of which was
+    /// stored more than the trusting period ago. Returns that height.
+    fn store_consensus_states_with_expired(
+        ctx: &mut MockContext,
+        client_id: &ClientId,
+        client_state: &ClientState,
+        tm_header: &tendermint::block::Header,
+    ) -> Height {
+        let latest_height = client_state.latest_height;
+        let old_height = latest_height.sub(2).unwrap();
+
+        let now = ValidationContext::host_timestamp(&*ctx).unwrap();
+        let host_height = ValidationContext::host_height(&*ctx).unwrap();
+        let old_update_time =
+            (now - (client_state.trusting_period + Duration::from_secs(1))).unwrap();
+
+        for (height, update_time) in [
+            (old_height, old_update_time),
+            (latest_height.sub(1).unwrap(), now),
+            (latest_height, now),
+        ] {
+            ctx.store_consensus_state(
+                ClientConsensusStatePath::new(client_id, &height),
+                TmConsensusState::from(tm_header.clone()).into(),
+            )
+            .unwrap();
+            ctx.store_update_time(client_id.clone(), height, update_time)
+                .unwrap();
+            ctx.store_update_height(client_id.clone(), height, host_height)
+                .unwrap();
+        }
+
+        old_height
+    }
+
+    #[test]
+    fn prune_expired_consensus_states() {
+        let client_id = ClientId::new(tm_client_type(), 0).unwrap();
+        let tm_header = get_dummy_tendermint_header();
+        let client_state = ClientState::new_dummy_from_header(tm_header.clone());
+
+        let latest_height = client_state.latest_height;
+        let recent_height = latest_height.sub(1).unwrap();
+
+        let mut ctx = MockContext::default();
+        let old_height =
+            store_consensus_states_with_expired(&mut ctx, &client_id, &client_state, &tm_header);
+
+        let (host_timestamp, update_times) = ctx.consensus_states_for_pruning(&client_id).unwrap();
+        let pruned_heights = client_state
+            .prune_expired_consensus_states(&mut ctx, &client_id, &host_timestamp, update_times)
+            .unwrap();
+
+        assert_eq!(pruned_heights, vec![old_height]);
+        assert_eq!(
+            ctx.consensus_state_heights(&client_id).unwrap(),
+            vec![recent_height, latest_height]
+        );
+        // The update metadata of the pruned height is deleted with it
+        assert!(ValidationContext::client_update_time(&ctx, &client_id, &old_height).is_err());
+        assert!(ValidationContext::client_update_height(&ctx, &client_id, &old_height).is_err());
+        assert!(ValidationContext::client_update_time(&ctx, &client_id, &recent_height).is_ok());
+    }
+
+    #[test]
+    fn update_state_prunes_expired_consensus_states_when_enabled() {
+        let chain_id = ChainId::new("mockgaiaA", 1).unwrap();
+        let client_id = ClientId::new(tm_client_type(), 0).unwrap();
+        let header_at = |height| -> TmHeader {
+            HostBlock::generate_tm_block(chain_id.clone(), height, Timestamp::now()).into()
+        };
+
+        let tm_header = header_at(10).signed_header.header;
+        let client_state = ClientState::new_dummy_from_header(tm_header.clone());
+        let latest_height = client_state.latest_height;
+        let new_height = latest_height.increment();
+
+        for pruning_enabled in [false, true] {
+            let mut ctx = if pruning_enabled {
+                MockContext::default().with_pruning_enabled()
+            } else {
+                MockContext::default()
+            };
+            assert_eq!(ctx.is_pruning_enabled(), pruning_enabled);
+
+            let old_height = store_consensus_states_with_expired(
+                &mut ctx,
+                &client_id,
+                &client_state,
+                &tm_header,
+            );
+
+            let updated_heights = client_state
+                .update_state(&mut ctx, &client_id, header_at(11).into())
+                .unwrap();
+            assert_eq!(updated_heights, vec![new_height]);
+
+            let heights = ctx.consensus_state_heights(&client_id).unwrap();
+            assert_eq!(heights.contains(&old_height), !pruning_enabled);
+            assert!(heights.contains(&latest_height));
+            assert!(heights.contains(&new_height));
+        }
+    }
 }
 
 #[cfg(all(test, feature = "serde"))]
@@ -87,6 +87,24 @@ impl ClientState {
             }
         }
 
+        // ensure the header is not dated past the host clock, beyond the max clock drift
+        {
+            let header_timestamp = header.timestamp();
+            let max_header_timestamp =
+                (current_timestamp + self.max_clock_drift).map_err(|e| ClientError::Other {
+                    description: e.to_string(),
+                })?;
+
+            if header_timestamp > max_header_timestamp {
+                return Err(Error::MisbehaviourHeaderFromFuture {
+                    header_timestamp,
+                    current_timestamp,
+                    max_clock_drift: self.max_clock_drift,
+                }
+                .into());
+            }
+        }
+
         // main header verification, delegated to the tendermint-light-client crate.
         let untrusted_state = header.as_untrusted_block_state();
 
@@ -111,10 +129,18 @@ impl ClientState {
         Ok(())
     }
 
-    pub fn check_for_misbehaviour_misbehavior(
-        &self,
-        misbehaviour: &TmMisbehaviour,
-    ) -> Result<bool, ClientError> {
+    /// Determines whether the two headers carried by `misbehaviour` are
+    /// evidence of misbehaviour, without verifying them against any trusted
+    /// state. Relayers can use it to filter out submissions that the client
+    /// would not act upon.
+    ///
+    /// - If both headers are at the same height, there is misbehaviour only if
+    ///   they commit to different block hashes. The same header submitted
+    ///   twice is evidence of nothing.
+    /// - If `header1` is at a greater height than `header2`, there is
+    ///   misbehaviour only if `header1` is not strictly later than `header2`,
+    ///   i.e. BFT time failed to increase monotonically.
+    pub fn detect_misbehaviour(&self, misbehaviour: &TmMisbehaviour) -> Result<bool, ClientError> {
         let header_1 = misbehaviour.header1();
         let header_2 = misbehaviour.header2();
 
@@ -133,4 +159,12 @@ impl ClientState {
             Ok(header_1.signed_header.header.time <= header_2.signed_header.header.time)
         }
     }
+
+    #[deprecated(since = "0.43.0", note = "use `detect_misbehaviour` instead")]
+    pub fn check_for_misbehaviour_misbehavior(
+        &self,
+        misbehaviour: &TmMisbehaviour,
+    ) -> Result<bool, ClientError> {
+        self.detect_misbehaviour(misbehaviour)
+    }
 }
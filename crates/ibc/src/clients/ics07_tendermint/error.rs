@@ -3,7 +3,8 @@
 use crate::prelude::*;
 
 use crate::core::ics02_client::error::ClientError;
-use crate::core::ics24_host::identifier::{ClientId, IdentifierError};
+use crate::core::ics24_host::identifier::{ChainId, ClientId, IdentifierError};
+use crate::core::timestamp::Timestamp;
 use crate::Height;
 
 use core::time::Duration;
@@ -91,12 +92,28 @@ pub enum Error {
         duration_since_consensus_state: Duration,
         trusting_period: Duration,
     },
+    /// misbehaviour header timestamp `{header_timestamp}` is later than the host timestamp `{current_timestamp}` plus the max clock drift `{max_clock_drift:?}`
+    MisbehaviourHeaderFromFuture {
+        header_timestamp: Timestamp,
+        current_timestamp: Timestamp,
+        max_clock_drift: Duration,
+    },
     /// headers block hashes are equal
     MisbehaviourHeadersBlockHashesEqual,
     /// headers are not at same height and are monotonically increasing
     MisbehaviourHeadersNotAtSameHeight,
 }
 
+impl Error {
+    /// Builds a `MismatchHeaderChainId` error from typed chain ids.
+    pub fn mismatch_header_chain_id(given: &ChainId, expected: &ChainId) -> Self {
+        Self::MismatchHeaderChainId {
+            given: given.to_string(),
+            expected: expected.to_string(),
+        }
+    }
+}
+
 #[cfg(feature = "std")]
 impl std::error::Error for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
@@ -106,6 +123,10 @@ impl std::error::Error for Error {
             Self::InvalidTendermintTrustThreshold(e) => Some(e),
             Self::InvalidRawHeader(e) => Some(e),
             Self::Decode(e) => Some(e),
+            // `LightClientErrorDetail` is a flex-error detail enum, which only
+            // implements `Display` and not `std::error::Error`, so it cannot be
+            // exposed as a source. Its message is part of our `Display` output.
+            Self::VerificationError { .. } => None,
             _ => None,
         }
     }
@@ -257,6 +257,58 @@ mod pretty {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::mock::host::HostBlock;
+
+    #[test]
+    fn verify_chain_id_version_mismatch() {
+        let header_chain_id = ChainId::new("mockgaiaB", 1).unwrap();
+        let client_chain_id = ChainId::new("mockgaiaA", 2).unwrap();
+
+        let header: Header =
+            HostBlock::generate_tm_block(header_chain_id.clone(), 10, Timestamp::now()).into();
+
+        assert!(header
+            .verify_chain_id_version_matches_height(&header_chain_id)
+            .is_ok());
+
+        let err = header
+            .verify_chain_id_version_matches_height(&client_chain_id)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "the given chain-id (`mockgaiaB-1`) does not match the chain-id of the client (`mockgaiaA-2`)"
+        );
+        match err {
+            Error::MismatchHeaderChainId { given, expected } => {
+                assert_eq!(given, header_chain_id.to_string());
+                assert_eq!(expected, client_chain_id.to_string());
+            }
+            e => panic!("unexpected error: {e}"),
+        }
+    }
+
+    #[test]
+    fn mismatch_header_chain_id_from_typed_chain_ids() {
+        let given = ChainId::new("mockgaiaB", 1).unwrap();
+        let expected = ChainId::new("mockgaiaA", 2).unwrap();
+
+        let err = Error::mismatch_header_chain_id(&given, &expected);
+        assert_eq!(
+            err.to_string(),
+            "the given chain-id (`mockgaiaB-1`) does not match the chain-id of the client (`mockgaiaA-2`)"
+        );
+        assert!(matches!(
+            err,
+            Error::MismatchHeaderChainId { given: ref g, expected: ref e }
+                if g == given.as_str() && e == expected.as_str()
+        ));
+    }
+}
+
 #[cfg(any(test, feature = "mocks"))]
 pub mod test_util {
     use alloc::vec;
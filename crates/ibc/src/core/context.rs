@@ -16,7 +16,9 @@ use crate::core::ics03_connection::version::{
 };
 use crate::core::ics04_channel::channel::ChannelEnd;
 use crate::core::ics04_channel::commitment::{AcknowledgementCommitment, PacketCommitment};
-use crate::core::ics04_channel::context::calculate_block_delay;
+use crate::core::ics04_channel::context::{
+    calculate_block_delay, ChannelIdGenerator, SequentialChannelIdGenerator,
+};
 use crate::core::ics04_channel::error::ChannelError;
 use crate::core::ics04_channel::error::PacketError;
 use crate::core::ics04_channel::packet::{Receipt, Sequence};
@@ -120,6 +122,19 @@ pub trait ValidationContext: Router {
         client_cons_state_path: &ClientConsensusStatePath,
     ) -> Result<Self::AnyConsensusState, ContextError>;
 
+    /// Returns the heights of all the consensus states stored for the client
+    /// `client_id`, in ascending order.
+    ///
+    /// Hosts that can't enumerate the consensus states of a client may keep
+    /// the default implementation, which returns an error.
+    fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError> {
+        Err(ContextError::ClientError(ClientError::Other {
+            description: format!(
+                "listing the consensus state heights of client {client_id} is not supported"
+            ),
+        }))
+    }
+
     /// Returns the time when the client state for the given [`ClientId`] was updated with a header for the given [`Height`]
     fn client_update_time(
         &self,
@@ -226,6 +241,12 @@ pub trait ValidationContext: Router {
     /// `ExecutionContext::increase_channel_counter`.
     fn channel_counter(&self) -> Result<u64, ContextError>;
 
+    /// Returns the generator of the identifiers of newly created channels.
+    /// Defaults to sequential `channel-{n}` identifiers.
+    fn channel_id_generator(&self) -> &dyn ChannelIdGenerator {
+        &SequentialChannelIdGenerator
+    }
+
     /// Returns the maximum expected time per block
     fn max_expected_time_per_block(&self) -> Duration;
 
@@ -236,7 +257,8 @@ pub trait ValidationContext: Router {
     }
 
     /// Validates the `signer` field of IBC messages, which represents the address
-    /// of the user/relayer that signed the given message.
+    /// of the user/relayer that signed the given message. Hosts may use it to
+    /// reject signers that do not correspond to an existing account.
     fn validate_message_signer(&self, signer: &Signer) -> Result<(), ContextError>;
 }
 
@@ -328,6 +350,22 @@ pub trait ExecutionContext: ValidationContext {
         channel_end: ChannelEnd,
     ) -> Result<(), ContextError>;
 
+    /// Stores all the given channel ends at their associated paths, e.g. when
+    /// importing channels at genesis. Defaults to calling `store_channel` for
+    /// each entry, stopping at the first error.
+    fn store_channels(
+        &mut self,
+        entries: impl IntoIterator<Item = (ChannelEndPath, ChannelEnd)>,
+    ) -> Result<(), ContextError>
+    where
+        Self: Sized,
+    {
+        for (channel_end_path, channel_end) in entries {
+            self.store_channel(&channel_end_path, channel_end)?;
+        }
+        Ok(())
+    }
+
     /// Stores the given `nextSequenceSend` number at the given store path
     fn store_next_sequence_send(
         &mut self,
@@ -350,9 +388,10 @@ pub trait ExecutionContext: ValidationContext {
     ) -> Result<(), ContextError>;
 
     /// Called upon channel identifier creation (Init or Try message processing).
-    /// Increases the counter which keeps track of how many channels have been created.
+    /// Increases the counter which keeps track of how many channels have been created,
+    /// and returns its new value.
     /// Should never fail.
-    fn increase_channel_counter(&mut self);
+    fn increase_channel_counter(&mut self) -> u64;
 
     /// Emit the given IBC event
     fn emit_ibc_event(&mut self, event: IbcEvent);
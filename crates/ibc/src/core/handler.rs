@@ -354,7 +354,7 @@ mod tests {
             "ICS26 routing dispatch test 'client creation' failed for message {create_client_msg:?} with result: {res:?}",
         );
 
-        ctx.scope_port_to_module(msg_chan_init.port_id_on_a.clone(), transfer_module_id);
+        ctx.bind_port(msg_chan_init.port_id_on_a.clone(), transfer_module_id);
 
         // Figure out the ID of the client that was just created.
         assert!(matches!(
@@ -630,7 +630,7 @@ mod tests {
         ctx.add_route(module_id.clone(), module).unwrap();
 
         // Note: messages will be using the default port
-        ctx.scope_port_to_module(PortId::default(), module_id);
+        ctx.bind_port(PortId::default(), module_id);
 
         ctx
     }
@@ -656,6 +656,28 @@ mod tests {
         assert!(matches!(ctx.events[1], IbcEvent::OpenInitChannel(_)));
     }
 
+    #[test]
+    fn test_chan_open_init_unbound_port() {
+        let mut ctx = get_channel_events_ctx();
+
+        let mut msg_chan_open_init =
+            MsgChannelOpenInit::try_from(get_dummy_raw_msg_chan_open_init(None)).unwrap();
+        msg_chan_open_init.port_id_on_a = PortId::transfer();
+
+        let res = dispatch(
+            &mut ctx,
+            MsgEnvelope::Channel(ChannelMsg::OpenInit(msg_chan_open_init)),
+        );
+
+        match res {
+            Err(RouterError::ContextError(ContextError::ChannelError(
+                ChannelError::PortNotBound { port_id },
+            ))) => assert_eq!(port_id, PortId::transfer()),
+            res => panic!("expected a port not bound error, got {res:?}"),
+        }
+        assert!(ctx.events.is_empty());
+    }
+
     #[test]
     fn test_chan_open_try_event() {
         let mut ctx = get_channel_events_ctx();
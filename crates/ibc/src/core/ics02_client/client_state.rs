@@ -208,8 +208,16 @@ where
 /// ClientExecutionContext = <...>)]` which specifies [`ClientState`]'s generic
 /// arguments to be defined.
 ///
-/// The macro does not support generic types for `ClientValidationContext` and
-/// `ClientExecutionContext` (e.g. `MyType<T>` would not be supported).
+/// The contexts can be a single generic parameter, declared along with its
+/// trait bounds in the `generic` argument, e.g. `#[generics(ClientValidationContext
+/// = Ctx, ClientExecutionContext = Ctx, generic = "Ctx: MyBound")]`. Generic
+/// types (e.g. `MyType<T>`) are otherwise not supported.
+///
+/// If the variants are annotated with `#[type_url = "..."]`, the macro also
+/// implements `From<Enum> for Any` and `TryFrom<Any> for Enum`, dispatching on
+/// the type URL of each variant, along with a `verify_client_state_type` method
+/// rejecting unknown type URLs before any decoding. In that case, a variant
+/// without the attribute is a compile error.
 pub use ibc_derive::ClientState;
 
 /// Primary client trait. Defines all the methods that clients must implement.
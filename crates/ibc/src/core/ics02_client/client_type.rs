@@ -54,3 +54,21 @@ impl Display for ClientType {
         write!(f, "ClientType({})", self.0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn client_type_validation() {
+        let client_type = ClientType::new("07-tendermint").unwrap();
+        assert_eq!(client_type.as_str(), "07-tendermint");
+        assert_eq!(ClientType::from_str("07-tendermint").unwrap(), client_type);
+
+        assert!(matches!(ClientType::new(""), Err(IdentifierError::Empty)));
+        assert!(matches!(
+            ClientType::new("07/tendermint"),
+            Err(IdentifierError::ContainSeparator { .. })
+        ));
+    }
+}
@@ -9,6 +9,11 @@ use crate::core::timestamp::Timestamp;
 
 /// Derive macro that implements [`ConsensusState`] for enums containing
 /// variants that implement [`ConsensusState`]
+///
+/// If the variants are annotated with `#[type_url = "..."]`, the macro also
+/// generates a `decode_any` method, which decodes an `Any` into the variant
+/// matching its type URL. In that case, a variant without the attribute is a
+/// compile error.
 pub use ibc_derive::ConsensusState;
 
 /// Defines methods that all `ConsensusState`s should provide.
@@ -1,8 +1,13 @@
+use crate::prelude::*;
+
 use super::client_state::ClientState;
 use super::consensus_state::ConsensusState;
+use crate::core::ics24_host::identifier::ClientId;
 use crate::core::ics24_host::path::ClientConsensusStatePath;
 use crate::core::ics24_host::path::ClientStatePath;
+use crate::core::timestamp::Timestamp;
 use crate::core::ContextError;
+use crate::Height;
 
 /// Defines the methods that all client `ExecutionContext`s (precisely the
 /// generic parameter of
@@ -30,4 +35,56 @@ pub trait ClientExecutionContext: Sized {
         consensus_state_path: ClientConsensusStatePath,
         consensus_state: Self::AnyConsensusState,
     ) -> Result<(), ContextError>;
+
+    /// Deletes the consensus state at the given path, e.g. when pruning
+    /// consensus states that have expired.
+    ///
+    /// The processed time and height recorded for the client at that height
+    /// must be deleted along with the consensus state.
+    fn delete_consensus_state(
+        &mut self,
+        consensus_state_path: ClientConsensusStatePath,
+    ) -> Result<(), ContextError>;
+
+    /// Whether clients delete their expired consensus states when they are
+    /// updated. Disabled by default.
+    ///
+    /// Hosts that enable pruning should also override
+    /// [`Self::consensus_states_for_pruning`], without which nothing is
+    /// pruned.
+    fn is_pruning_enabled(&self) -> bool {
+        false
+    }
+
+    /// Returns the current timestamp of the local chain, along with the
+    /// heights of the consensus states stored for the client `client_id` in
+    /// ascending order and the local time at which each of them was stored.
+    ///
+    /// Only called when [`Self::is_pruning_enabled`] holds. By default no
+    /// consensus state is reported, so none is pruned.
+    fn consensus_states_for_pruning(
+        &self,
+        _client_id: &ClientId,
+    ) -> Result<(Timestamp, Vec<(Height, Timestamp)>), ContextError> {
+        Ok((Timestamp::none(), Vec::new()))
+    }
+
+    /// Called by the ICS-2 handlers after a client state was stored at the
+    /// given path, e.g. to mirror it to an external index. Does nothing by
+    /// default.
+    fn on_store_client_state(
+        &mut self,
+        _client_state_path: &ClientStatePath,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
+
+    /// Called by the ICS-2 handlers after a consensus state was stored at the
+    /// given path. Does nothing by default.
+    fn on_store_consensus_state(
+        &mut self,
+        _consensus_state_path: &ClientConsensusStatePath,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
 }
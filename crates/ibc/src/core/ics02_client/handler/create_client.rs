@@ -10,7 +10,9 @@ use crate::core::ics02_client::client_state::ClientStateExecution;
 use crate::core::ics02_client::error::ClientError;
 use crate::core::ics02_client::events::CreateClient;
 use crate::core::ics02_client::msgs::create_client::MsgCreateClient;
+use crate::core::ics02_client::ClientExecutionContext;
 use crate::core::ics24_host::identifier::ClientId;
+use crate::core::ics24_host::path::{ClientConsensusStatePath, ClientStatePath};
 use crate::core::ExecutionContext;
 use crate::core::ValidationContext;
 
@@ -83,6 +85,13 @@ where
 
     let latest_height = client_state.latest_height();
 
+    {
+        let client_exec_ctx = ctx.get_client_execution_context();
+        client_exec_ctx.on_store_client_state(&ClientStatePath::new(&client_id))?;
+        client_exec_ctx
+            .on_store_consensus_state(&ClientConsensusStatePath::new(&client_id, &latest_height))?;
+    }
+
     ctx.store_update_time(client_id.clone(), latest_height, ctx.host_timestamp()?)?;
     ctx.store_update_height(client_id.clone(), latest_height, ctx.host_height()?)?;
     ctx.increase_client_counter();
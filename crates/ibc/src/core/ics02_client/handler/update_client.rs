@@ -11,6 +11,8 @@ use crate::core::ics02_client::client_state::UpdateKind;
 use crate::core::ics02_client::error::ClientError;
 use crate::core::ics02_client::events::{ClientMisbehaviour, UpdateClient};
 use crate::core::ics02_client::msgs::MsgUpdateOrMisbehaviour;
+use crate::core::ics02_client::ClientExecutionContext;
+use crate::core::ics24_host::path::{ClientConsensusStatePath, ClientStatePath};
 use crate::core::{ExecutionContext, ValidationContext};
 
 pub(crate) fn validate<Ctx>(ctx: &Ctx, msg: MsgUpdateOrMisbehaviour) -> Result<(), ContextError>
@@ -28,7 +30,11 @@ where
     // Read client state from the host chain store. The client should already exist.
     let client_state = ctx.client_state(&client_id)?;
 
-    client_state.confirm_not_frozen()?;
+    // Misbehaviour may still be submitted against a frozen client, since
+    // freezing it again is harmless.
+    if matches!(update_kind, UpdateKind::UpdateClient) {
+        client_state.confirm_not_frozen()?;
+    }
 
     let client_message = msg.client_message();
 
@@ -70,6 +76,9 @@ where
             &update_kind,
         )?;
 
+        ctx.get_client_execution_context()
+            .on_store_client_state(&ClientStatePath::new(&client_id))?;
+
         let event = IbcEvent::ClientMisbehaviour(ClientMisbehaviour::new(
             client_id,
             client_state.client_type(),
@@ -92,6 +101,17 @@ where
             header.clone(),
         )?;
 
+        {
+            let client_exec_ctx = ctx.get_client_execution_context();
+            client_exec_ctx.on_store_client_state(&ClientStatePath::new(&client_id))?;
+            for consensus_height in consensus_heights.iter() {
+                client_exec_ctx.on_store_consensus_state(&ClientConsensusStatePath::new(
+                    &client_id,
+                    consensus_height,
+                ))?;
+            }
+        }
+
         // Store host height and time for all updated headers
         {
             let host_timestamp = ctx.host_timestamp()?;
@@ -103,6 +123,8 @@ where
             }
         }
 
+        // The primary event reports the first updated height, while every
+        // updated height is observable through its `consensus_heights`.
         {
             let event = {
                 let consensus_height = consensus_heights.get(0).ok_or(ClientError::Other {
@@ -143,17 +165,20 @@ mod tests {
     use crate::core::ics02_client::handler::update_client::{execute, validate};
     use crate::core::ics02_client::msgs::misbehaviour::MsgSubmitMisbehaviour;
     use crate::core::ics02_client::msgs::update_client::MsgUpdateClient;
+    use crate::core::ics02_client::msgs::ClientMsg;
     use crate::core::ics23_commitment::specs::ProofSpecs;
     use crate::core::ics24_host::identifier::{ChainId, ClientId};
     use crate::core::timestamp::Timestamp;
+    use crate::core::MsgEnvelope;
     use crate::downcast;
     use crate::mock::client_state::client_type as mock_client_type;
     use crate::mock::client_state::MockClientState;
-    use crate::mock::context::{AnyConsensusState, MockContext};
+    use crate::mock::consensus_state::MockConsensusState;
+    use crate::mock::context::{AnyConsensusState, MockContext, StoreDiff};
     use crate::mock::header::MockHeader;
-    use crate::mock::host::{HostBlock, HostType};
+    use crate::mock::host::{mock_chain_id_a, mock_chain_id_b, HostBlock, HostType};
     use crate::mock::misbehaviour::Misbehaviour as MockMisbehaviour;
-    use crate::test_utils::get_dummy_account_id;
+    use crate::test_utils::{assert_validate_idempotent, get_dummy_account_id};
     use crate::Height;
     use ibc_proto::ibc::lightclients::tendermint::v1::{ClientState as RawTmClientState, Fraction};
 
@@ -176,6 +201,12 @@ mod tests {
 
         assert!(res.is_ok(), "validation happy path");
 
+        let res = assert_validate_idempotent(
+            &ctx,
+            MsgEnvelope::Client(ClientMsg::UpdateClient(msg.clone())),
+        );
+        assert!(res.is_ok(), "validation happy path through the router");
+
         let res = execute(&mut ctx, MsgUpdateOrMisbehaviour::UpdateClient(msg.clone()));
         assert!(res.is_ok(), "execution happy path");
 
@@ -185,6 +216,82 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_update_client_store_observer() {
+        let client_id = ClientId::default();
+        let signer = get_dummy_account_id();
+
+        let mut ctx = MockContext::default().with_client(&client_id, Height::new(0, 42).unwrap());
+        let height = Height::new(0, 46).unwrap();
+        let header = MockHeader::new(height).with_timestamp(Timestamp::now());
+        let msg = MsgUpdateClient {
+            client_id: client_id.clone(),
+            header: header.into(),
+            signer,
+        };
+
+        let res = execute(&mut ctx, MsgUpdateOrMisbehaviour::UpdateClient(msg));
+        assert!(res.is_ok(), "execution happy path");
+
+        assert_eq!(
+            ctx.store_diffs,
+            vec![
+                StoreDiff::ClientState(
+                    ClientStatePath::new(&client_id),
+                    MockClientState::new(header).into()
+                ),
+                StoreDiff::ConsensusState(
+                    ClientConsensusStatePath::new(&client_id, &height),
+                    MockConsensusState::new(header).into()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_update_client_batched_heights() {
+        let client_id = ClientId::default();
+        let signer = get_dummy_account_id();
+
+        let mut ctx = MockContext::default().with_client(&client_id, Height::new(0, 42).unwrap());
+        let client_state = MockClientState::new(MockHeader::new(Height::new(0, 42).unwrap()))
+            .with_update_batch_size(2);
+        ctx.ibc_store
+            .lock()
+            .clients
+            .get_mut(&client_id)
+            .expect("client exists")
+            .client_state = Some(client_state.into());
+
+        let height = Height::new(0, 46).unwrap();
+        let msg = MsgUpdateClient {
+            client_id: client_id.clone(),
+            header: MockHeader::new(height).into(),
+            signer,
+        };
+
+        let res = execute(&mut ctx, MsgUpdateOrMisbehaviour::UpdateClient(msg));
+        assert!(res.is_ok(), "execution happy path");
+
+        let update_client_event = ctx
+            .events
+            .iter()
+            .find_map(|event| match event {
+                IbcEvent::UpdateClient(e) => Some(e),
+                _ => None,
+            })
+            .expect("an update client event must be emitted");
+
+        let expected_heights = [height, Height::new(0, 45).unwrap()];
+        assert_eq!(update_client_event.consensus_height(), &height);
+        assert_eq!(update_client_event.consensus_heights(), &expected_heights);
+        for consensus_height in expected_heights {
+            assert!(ctx
+                .client_update_height(&client_id, &consensus_height)
+                .is_ok());
+        }
+    }
+
     #[test]
     fn test_update_nonexisting_client() {
         let client_id = ClientId::from_str("mockclient1").unwrap();
@@ -192,15 +299,21 @@ mod tests {
 
         let ctx = MockContext::default().with_client(&client_id, Height::new(0, 42).unwrap());
 
+        let nonexisting_client_id = ClientId::from_str("nonexistingclient").unwrap();
         let msg = MsgUpdateClient {
-            client_id: ClientId::from_str("nonexistingclient").unwrap(),
+            client_id: nonexisting_client_id.clone(),
             header: MockHeader::new(Height::new(0, 46).unwrap()).into(),
             signer,
         };
 
         let res = validate(&ctx, MsgUpdateOrMisbehaviour::UpdateClient(msg));
 
-        assert!(res.is_err());
+        match res {
+            Err(e @ ContextError::ClientError(ClientError::ClientStateNotFound { .. })) => {
+                assert!(e.to_string().contains(nonexisting_client_id.as_str()))
+            }
+            _ => panic!("expected a client state not found error, got {res:?}"),
+        }
     }
 
     #[test]
@@ -208,10 +321,10 @@ mod tests {
         let client_id = ClientId::new(tm_client_type(), 0).unwrap();
         let client_height = Height::new(1, 20).unwrap();
         let update_height = Height::new(1, 21).unwrap();
-        let chain_id_b = ChainId::new("mockgaiaB", 1).unwrap();
+        let chain_id_b = mock_chain_id_b();
 
         let mut ctx = MockContext::new(
-            ChainId::new("mockgaiaA", 1).unwrap(),
+            mock_chain_id_a(),
             HostType::Mock,
             5,
             Height::new(1, 1).unwrap(),
@@ -254,10 +367,10 @@ mod tests {
         let client_id = ClientId::new(tm_client_type(), 0).unwrap();
         let client_height = Height::new(1, 20).unwrap();
         let update_height = Height::new(1, 21).unwrap();
-        let chain_id_b = ChainId::new("mockgaiaB", 1).unwrap();
+        let chain_id_b = mock_chain_id_b();
 
         let mut ctx = MockContext::new(
-            ChainId::new("mockgaiaA", 1).unwrap(),
+            mock_chain_id_a(),
             HostType::Mock,
             5,
             Height::new(1, 1).unwrap(),
@@ -301,8 +414,8 @@ mod tests {
         let client_id = ClientId::new(tm_client_type(), 0).unwrap();
         let client_height = Height::new(1, 20).unwrap();
 
-        let ctx_a_chain_id = ChainId::new("mockgaiaA", 1).unwrap();
-        let ctx_b_chain_id = ChainId::new("mockgaiaB", 1).unwrap();
+        let ctx_a_chain_id = mock_chain_id_a();
+        let ctx_b_chain_id = mock_chain_id_b();
         let start_height = Height::new(1, 11).unwrap();
 
         let mut ctx_a = MockContext::new(ctx_a_chain_id, HostType::Mock, 5, start_height)
@@ -416,6 +529,7 @@ mod tests {
         assert!(client_state.confirm_not_frozen().is_ok());
         assert_eq!(client_state.latest_height(), latest_header_height);
         assert_eq!(client_state, ctx_a.latest_client_states(&msg.client_id));
+        ctx_a.assert_client_consistent(&msg.client_id);
     }
 
     #[test]
@@ -427,21 +541,16 @@ mod tests {
 
         let chain_start_height = Height::new(1, 11).unwrap();
 
-        let ctx = MockContext::new(
-            ChainId::new("mockgaiaA", 1).unwrap(),
-            HostType::Mock,
-            5,
-            chain_start_height,
-        )
-        .with_client_parametrized(
-            &client_id,
-            client_height,
-            Some(tm_client_type()), // The target host chain (B) is synthetic TM.
-            Some(client_height),
-        );
+        let ctx = MockContext::new(mock_chain_id_a(), HostType::Mock, 5, chain_start_height)
+            .with_client_parametrized(
+                &client_id,
+                client_height,
+                Some(tm_client_type()), // The target host chain (B) is synthetic TM.
+                Some(client_height),
+            );
 
         let ctx_b = MockContext::new(
-            ChainId::new("mockgaiaB", 1).unwrap(),
+            mock_chain_id_b(),
             HostType::SyntheticTendermint,
             5,
             client_height,
@@ -539,6 +648,48 @@ mod tests {
         ensure_misbehaviour(&ctx, &client_id, &mock_client_type());
     }
 
+    /// Tests that misbehaviour can be submitted against an already frozen client,
+    /// while regular updates are rejected
+    #[test]
+    fn test_misbehaviour_frozen_client() {
+        let client_id = ClientId::default();
+        let timestamp = Timestamp::now();
+        let height = Height::new(0, 46).unwrap();
+        let msg = MsgSubmitMisbehaviour {
+            client_id: client_id.clone(),
+            misbehaviour: MockMisbehaviour {
+                client_id: client_id.clone(),
+                header1: MockHeader::new(height).with_timestamp(timestamp),
+                header2: MockHeader::new(height).with_timestamp(timestamp),
+            }
+            .into(),
+            signer: get_dummy_account_id(),
+        };
+
+        let mut ctx = MockContext::default().with_client(&client_id, Height::new(0, 42).unwrap());
+
+        let res = execute(&mut ctx, MsgUpdateOrMisbehaviour::Misbehaviour(msg.clone()));
+        assert!(res.is_ok());
+        assert!(ctx
+            .client_state(&client_id)
+            .unwrap()
+            .confirm_not_frozen()
+            .is_err());
+
+        let res = validate(&ctx, MsgUpdateOrMisbehaviour::Misbehaviour(msg));
+        assert!(res.is_ok());
+
+        let msg = MsgUpdateClient {
+            client_id,
+            header: MockHeader::new(Height::new(0, 47).unwrap())
+                .with_timestamp(timestamp)
+                .into(),
+            signer: get_dummy_account_id(),
+        };
+        let res = validate(&ctx, MsgUpdateOrMisbehaviour::UpdateClient(msg));
+        assert!(res.is_err());
+    }
+
     /// Tests misbehaviour handling failure for a non-existent client
     #[test]
     fn test_misbehaviour_nonexisting_client() {
@@ -567,11 +718,11 @@ mod tests {
         let client_id = ClientId::new(tm_client_type(), 0).unwrap();
         let client_height = Height::new(1, 20).unwrap();
         let misbehaviour_height = Height::new(1, 21).unwrap();
-        let chain_id_b = ChainId::new("mockgaiaB", 1).unwrap();
+        let chain_id_b = mock_chain_id_b();
 
         // Create a mock context for chain-A with a synthetic tendermint light client for chain-B
         let mut ctx_a = MockContext::new(
-            ChainId::new("mockgaiaA", 1).unwrap(),
+            mock_chain_id_a(),
             HostType::Mock,
             5,
             Height::new(1, 1).unwrap(),
@@ -623,16 +774,79 @@ mod tests {
         ensure_misbehaviour(&ctx_a, &client_id, &tm_client_type());
     }
 
+    #[test]
+    fn test_misbehaviour_synthetic_tendermint_header_from_future() {
+        let client_id = ClientId::new(tm_client_type(), 0).unwrap();
+        let client_height = Height::new(1, 20).unwrap();
+        let misbehaviour_height = Height::new(1, 21).unwrap();
+        let chain_id_b = mock_chain_id_b();
+
+        // Create a mock context for chain-A with a synthetic tendermint light client for chain-B
+        let ctx_a = MockContext::new(
+            mock_chain_id_a(),
+            HostType::Mock,
+            5,
+            Height::new(1, 1).unwrap(),
+        )
+        .with_client_parametrized_with_chain_id(
+            chain_id_b.clone(),
+            &client_id,
+            client_height,
+            Some(tm_client_type()),
+            Some(client_height),
+        );
+
+        // Create a mock context for chain-B
+        let ctx_b = MockContext::new(
+            chain_id_b.clone(),
+            HostType::SyntheticTendermint,
+            5,
+            misbehaviour_height,
+        );
+
+        // Get chain-B's header at `misbehaviour_height`
+        let header1: TmHeader = {
+            let mut block = ctx_b.host_block(&misbehaviour_height).unwrap().clone();
+            block.set_trusted_height(client_height);
+            block.try_into_tm_block().unwrap().into()
+        };
+
+        // Generate an equivocal header for chain-B at `misbehaviour_height`, dated
+        // well past the client's max clock drift
+        let header2 = {
+            let mut tm_block = HostBlock::generate_tm_block(
+                chain_id_b,
+                misbehaviour_height.revision_height(),
+                (Timestamp::now() + Duration::from_secs(3600)).unwrap(),
+            );
+            tm_block.trusted_height = client_height;
+            tm_block.into()
+        };
+
+        let msg = MsgSubmitMisbehaviour {
+            client_id: client_id.clone(),
+            misbehaviour: TmMisbehaviour::new(client_id, header1, header2).into(),
+            signer: get_dummy_account_id(),
+        };
+
+        let res = validate(&ctx_a, MsgUpdateOrMisbehaviour::Misbehaviour(msg));
+        assert!(matches!(
+            res,
+            Err(ContextError::ClientError(ClientError::ClientSpecific { ref description }))
+                if description.starts_with("misbehaviour header timestamp")
+        ));
+    }
+
     #[test]
     fn test_misbehaviour_synthetic_tendermint_bft_time() {
         let client_id = ClientId::new(tm_client_type(), 0).unwrap();
         let client_height = Height::new(1, 20).unwrap();
         let misbehaviour_height = Height::new(1, 21).unwrap();
-        let chain_id_b = ChainId::new("mockgaiaB", 1).unwrap();
+        let chain_id_b = mock_chain_id_b();
 
         // Create a mock context for chain-A with a synthetic tendermint light client for chain-B
         let mut ctx_a = MockContext::new(
-            ChainId::new("mockgaiaA", 1).unwrap(),
+            mock_chain_id_a(),
             HostType::Mock,
             5,
             Height::new(1, 1).unwrap(),
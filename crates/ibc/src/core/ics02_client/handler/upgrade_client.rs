@@ -10,7 +10,8 @@ use crate::core::ics02_client::consensus_state::ConsensusState;
 use crate::core::ics02_client::error::ClientError;
 use crate::core::ics02_client::events::UpgradeClient;
 use crate::core::ics02_client::msgs::upgrade_client::MsgUpgradeClient;
-use crate::core::ics24_host::path::ClientConsensusStatePath;
+use crate::core::ics02_client::ClientExecutionContext;
+use crate::core::ics24_host::path::{ClientConsensusStatePath, ClientStatePath};
 use crate::core::{ExecutionContext, ValidationContext};
 
 pub(crate) fn validate<Ctx>(ctx: &Ctx, msg: MsgUpgradeClient) -> Result<(), ContextError>
@@ -84,6 +85,13 @@ where
         msg.upgraded_consensus_state,
     )?;
 
+    {
+        let client_exec_ctx = ctx.get_client_execution_context();
+        client_exec_ctx.on_store_client_state(&ClientStatePath::new(&client_id))?;
+        client_exec_ctx
+            .on_store_consensus_state(&ClientConsensusStatePath::new(&client_id, &latest_height))?;
+    }
+
     let event = IbcEvent::UpgradeClient(UpgradeClient::new(
         client_id,
         old_client_state.client_type(),
@@ -65,6 +65,11 @@ impl Height {
         self.revision_height
     }
 
+    /// Returns the `(revision_number, revision_height)` pair
+    pub fn revision(&self) -> (u64, u64) {
+        (self.revision_number, self.revision_height)
+    }
+
     pub fn add(&self, delta: u64) -> Height {
         Height {
             revision_number: self.revision_number,
@@ -72,6 +77,14 @@ impl Height {
         }
     }
 
+    /// Adds `delta` to the revision height, returning `None` on overflow.
+    pub fn checked_add(&self, delta: u64) -> Option<Height> {
+        Some(Height {
+            revision_number: self.revision_number,
+            revision_height: self.revision_height.checked_add(delta)?,
+        })
+    }
+
     pub fn increment(&self) -> Height {
         self.add(1)
     }
@@ -92,6 +105,13 @@ impl Height {
     }
 }
 
+impl PartialEq<(u64, u64)> for Height {
+    /// Compares against a `(revision_number, revision_height)` tuple
+    fn eq(&self, other: &(u64, u64)) -> bool {
+        self.revision_number == other.0 && self.revision_height == other.1
+    }
+}
+
 impl PartialOrd for Height {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
@@ -262,3 +282,28 @@ fn test_invalid_height() {
         })
     );
 }
+
+#[test]
+fn test_height_eq_tuple() {
+    let height = Height::new(1, 20).unwrap();
+
+    assert_eq!(height, (1, 20));
+    assert_ne!(height, (1, 21));
+    assert_ne!(height, (2, 20));
+}
+
+#[test]
+fn test_height_revision() {
+    let (revision_number, revision_height) = Height::new(1, 20).unwrap().revision();
+
+    assert_eq!(revision_number, 1);
+    assert_eq!(revision_height, 20);
+}
+
+#[test]
+fn test_height_checked_add() {
+    let height = Height::new(1, 20).unwrap();
+
+    assert_eq!(height.checked_add(5), Some(Height::new(1, 25).unwrap()));
+    assert_eq!(Height::new(1, u64::MAX).unwrap().checked_add(1), None);
+}
@@ -17,6 +17,7 @@ use ibc_proto::ibc::core::connection::v1::{
 use crate::core::ics02_client::error::ClientError;
 use crate::core::ics03_connection::error::ConnectionError;
 use crate::core::ics03_connection::version::Version;
+use crate::core::ics04_channel::channel::Order;
 use crate::core::ics23_commitment::commitment::CommitmentPrefix;
 use crate::core::ics24_host::identifier::{ClientId, ConnectionId};
 use crate::core::timestamp::ZERO_DURATION;
@@ -353,6 +354,20 @@ impl ConnectionEnd {
         &self.versions
     }
 
+    /// Checks that at least one of the versions of this connection end
+    /// supports channels with the given ordering.
+    pub fn verify_supports_ordering(&self, order: Order) -> Result<(), ConnectionError> {
+        let feature = order.as_str().to_string();
+        if !self
+            .versions
+            .iter()
+            .any(|version| version.verify_feature_supported(feature.clone()).is_ok())
+        {
+            return Err(ConnectionError::OrderingNotSupported { ordering: order });
+        }
+        Ok(())
+    }
+
     /// Getter for the counterparty.
     pub fn counterparty(&self) -> &Counterparty {
         &self.counterparty
@@ -562,3 +577,43 @@ impl From<State> for i32 {
         value.into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ibc_proto::ibc::core::connection::v1::Version as RawVersion;
+
+    fn conn_end_with_features(features: &[Order]) -> ConnectionEnd {
+        let version = Version::try_from(RawVersion {
+            identifier: "1".to_string(),
+            features: features.iter().map(|order| order.to_string()).collect(),
+        })
+        .unwrap();
+
+        ConnectionEnd::new(
+            State::Open,
+            ClientId::default(),
+            Counterparty::default(),
+            vec![version],
+            ZERO_DURATION,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn verify_supports_ordering() {
+        let conn_end = conn_end_with_features(&[Order::Ordered, Order::Unordered]);
+        assert!(conn_end.verify_supports_ordering(Order::Ordered).is_ok());
+        assert!(conn_end.verify_supports_ordering(Order::Unordered).is_ok());
+
+        let conn_end = conn_end_with_features(&[Order::Unordered]);
+        assert!(conn_end.verify_supports_ordering(Order::Unordered).is_ok());
+        assert!(matches!(
+            conn_end.verify_supports_ordering(Order::Ordered),
+            Err(ConnectionError::OrderingNotSupported {
+                ordering: Order::Ordered
+            })
+        ));
+    }
+}
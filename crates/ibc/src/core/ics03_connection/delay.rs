@@ -2,6 +2,12 @@ use crate::core::{ics02_client::height::Height, ContextError, ValidationContext}
 
 use super::{connection::ConnectionEnd, error::ConnectionError};
 
+/// Verifies that the delay period of `connection_end` has passed since the
+/// client was updated to `packet_proof_height`, both in time and in blocks.
+///
+/// Fails with [`ConnectionError::NotEnoughTimeElapsed`] if the time delay has
+/// not passed yet, and with [`ConnectionError::NotEnoughBlocksElapsed`] if the
+/// block delay derived from it has not.
 pub fn verify_conn_delay_passed<Ctx>(
     ctx: &Ctx,
     packet_proof_height: Height,
@@ -46,3 +52,131 @@ where
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::time::Duration;
+
+    use crate::core::ics03_connection::connection::{Counterparty, State};
+    use crate::core::ics03_connection::version::get_compatible_versions;
+    use crate::core::ics24_host::identifier::{ChainId, ClientId};
+    use crate::core::timestamp::Timestamp;
+    use crate::mock::context::MockContext;
+    use crate::mock::host::HostType;
+
+    /// A connection with a delay period of 10 seconds, on a host with a block
+    /// time of 1 second (i.e. a block delay of 10 blocks) at height 20.
+    fn fixture() -> (MockContext, ConnectionEnd) {
+        let client_id = ClientId::default();
+        let ctx = MockContext::new(
+            ChainId::new("mockgaia", 0).unwrap(),
+            HostType::Mock,
+            5,
+            Height::new(0, 20).unwrap(),
+        )
+        .with_block_time(Duration::from_secs(1))
+        .with_client(&client_id, Height::new(0, 10).unwrap());
+
+        let conn_end = ConnectionEnd::new(
+            State::Open,
+            client_id.clone(),
+            Counterparty::new(client_id, None, Default::default()),
+            get_compatible_versions(),
+            Duration::from_secs(10),
+        )
+        .unwrap();
+
+        (ctx, conn_end)
+    }
+
+    /// Records the client update to the proof height at the given host time
+    /// and height.
+    fn set_client_update(
+        ctx: &MockContext,
+        conn_end: &ConnectionEnd,
+        proof_height: Height,
+        update_time: Timestamp,
+        update_height: Height,
+    ) {
+        let mut ibc_store = ctx.ibc_store.lock();
+        let key = (conn_end.client_id().clone(), proof_height);
+        ibc_store
+            .client_processed_times
+            .insert(key.clone(), update_time);
+        ibc_store
+            .client_processed_heights
+            .insert(key, update_height);
+    }
+
+    #[test]
+    fn delay_passed() {
+        let (ctx, conn_end) = fixture();
+        let proof_height = Height::new(0, 10).unwrap();
+        let host_time = ctx.host_timestamp().unwrap();
+
+        set_client_update(
+            &ctx,
+            &conn_end,
+            proof_height,
+            (host_time - Duration::from_secs(10)).unwrap(),
+            Height::new(0, 10).unwrap(),
+        );
+
+        assert!(verify_conn_delay_passed(&ctx, proof_height, &conn_end).is_ok());
+    }
+
+    #[test]
+    fn time_elapsed_but_not_blocks() {
+        let (ctx, conn_end) = fixture();
+        let proof_height = Height::new(0, 10).unwrap();
+        let host_time = ctx.host_timestamp().unwrap();
+
+        set_client_update(
+            &ctx,
+            &conn_end,
+            proof_height,
+            (host_time - Duration::from_secs(10)).unwrap(),
+            Height::new(0, 15).unwrap(),
+        );
+
+        let res = verify_conn_delay_passed(&ctx, proof_height, &conn_end);
+        assert!(matches!(
+            res,
+            Err(ContextError::ConnectionError(
+                ConnectionError::NotEnoughBlocksElapsed {
+                    current_host_height,
+                    earliest_valid_height,
+                }
+            )) if current_host_height == Height::new(0, 20).unwrap()
+                && earliest_valid_height == Height::new(0, 25).unwrap()
+        ));
+    }
+
+    #[test]
+    fn blocks_elapsed_but_not_time() {
+        let (ctx, conn_end) = fixture();
+        let proof_height = Height::new(0, 10).unwrap();
+        let host_time = ctx.host_timestamp().unwrap();
+
+        set_client_update(
+            &ctx,
+            &conn_end,
+            proof_height,
+            (host_time - Duration::from_secs(5)).unwrap(),
+            Height::new(0, 1).unwrap(),
+        );
+
+        let res = verify_conn_delay_passed(&ctx, proof_height, &conn_end);
+        assert!(matches!(
+            res,
+            Err(ContextError::ConnectionError(
+                ConnectionError::NotEnoughTimeElapsed {
+                    current_host_time,
+                    ..
+                }
+            )) if current_host_time == host_time
+        ));
+    }
+}
@@ -2,6 +2,7 @@
 
 use crate::core::ics02_client::error as client_error;
 use crate::core::ics03_connection::version::Version;
+use crate::core::ics04_channel::channel::Order;
 use crate::core::ics24_host::identifier::{ClientId, ConnectionId, IdentifierError};
 use crate::core::timestamp::{Timestamp, TimestampOverflowError};
 use crate::Height;
@@ -41,6 +42,8 @@ pub enum ConnectionError {
     FeatureNotSupported { feature: String },
     /// no common features
     NoCommonFeatures,
+    /// no connection version supports the `{ordering}` channel ordering
+    OrderingNotSupported { ordering: Order },
     /// missing proof height
     MissingProofHeight,
     /// missing consensus height
@@ -51,7 +54,7 @@ pub enum ConnectionError {
     VerifyConnectionState(client_error::ClientError),
     /// invalid signer error: `{reason}`
     InvalidSigner { reason: String },
-    /// no connection was found for the previous connection id provided `{connection_id}`
+    /// connection not found: `{connection_id}`
     ConnectionNotFound { connection_id: ConnectionId },
     /// invalid counterparty
     InvalidCounterparty,
@@ -2,7 +2,7 @@
 //! on the local chain with a client on a remote chain.
 
 pub mod connection;
-pub(crate) mod delay;
+pub mod delay;
 pub mod error;
 pub mod events;
 /// Message processing logic (protocol) for ICS 03.
@@ -3,6 +3,7 @@
 use crate::prelude::*;
 use crate::utils::pretty::PrettySlice;
 
+use core::cmp::Ordering;
 use core::fmt::{Display, Error as FmtError, Formatter};
 use core::str::FromStr;
 
@@ -47,6 +48,28 @@ impl IdentifiedChannelEnd {
     }
 }
 
+/// Orders channel ends by their `(port_id, channel_id)` identifiers, which
+/// uniquely identify a channel end on a given chain. Channel ends sharing
+/// both identifiers are ordered by their encoding, to stay consistent with
+/// `Eq`.
+impl PartialOrd for IdentifiedChannelEnd {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for IdentifiedChannelEnd {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&self.port_id, &self.channel_id)
+            .cmp(&(&other.port_id, &other.channel_id))
+            .then_with(|| {
+                self.channel_end
+                    .encode_vec()
+                    .cmp(&other.channel_end.encode_vec())
+            })
+    }
+}
+
 impl Protobuf<RawIdentifiedChannel> for IdentifiedChannelEnd {}
 
 impl TryFrom<RawIdentifiedChannel> for IdentifiedChannelEnd {
@@ -141,7 +164,16 @@ impl TryFrom<RawChannel> for ChannelEnd {
         let connection_hops = value
             .connection_hops
             .into_iter()
-            .map(|conn_id| ConnectionId::from_str(conn_id.as_str()))
+            .enumerate()
+            .map(|(index, conn_id)| {
+                ConnectionId::from_str(conn_id.as_str()).map_err(|error| {
+                    ChannelError::InvalidConnectionHop {
+                        index,
+                        raw: conn_id,
+                        error,
+                    }
+                })
+            })
             .collect::<Result<Vec<_>, _>>()?;
 
         let version = value.version.into();
@@ -232,6 +264,11 @@ impl ChannelEnd {
         &self.remote
     }
 
+    /// Returns a mutable reference to the counterparty of this channel end.
+    pub fn counterparty_mut(&mut self) -> &mut Counterparty {
+        &mut self.remote
+    }
+
     pub fn connection_hops(&self) -> &Vec<ConnectionId> {
         &self.connection_hops
     }
@@ -307,6 +344,19 @@ impl ChannelEnd {
         verify_connection_hops_length(&self.connection_hops, 1)
     }
 
+    /// Checks if the `connection_hops` has a length within `1..=max_hops`,
+    /// which allows validating multi-hop channels.
+    pub fn verify_connection_hops_within(&self, max_hops: usize) -> Result<(), ChannelError> {
+        let actual = self.connection_hops.len();
+        if !(1..=max_hops).contains(&actual) {
+            return Err(ChannelError::ConnectionHopsLengthOutOfRange {
+                max: max_hops,
+                actual,
+            });
+        }
+        Ok(())
+    }
+
     pub fn version_matches(&self, other: &Version) -> bool {
         self.version().eq(other)
     }
@@ -372,6 +422,16 @@ impl Counterparty {
         }
         Ok(())
     }
+
+    /// Called upon acknowledging a channel handshake on the host chain to
+    /// verify that the counterparty channel id has been set.
+    pub(crate) fn verify_nonempty_channel_id(&self) -> Result<&ChannelId, ChannelError> {
+        self.channel_id()
+            .ok_or_else(|| ChannelError::InvalidChannelId {
+                expected: "Counterparty channel id must be set".to_string(),
+                actual: format!("{:?}", self.channel_id),
+            })
+    }
 }
 
 impl Display for Counterparty {
@@ -484,11 +544,13 @@ impl FromStr for Order {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().trim_start_matches("order_") {
-            "uninitialized" => Ok(Self::None),
+            "uninitialized" | "none_unspecified" => Ok(Self::None),
             "unordered" => Ok(Self::Unordered),
             "ordered" => Ok(Self::Ordered),
             _ => Err(ChannelError::InvalidOrderType {
-                expected: "Must be one of 'uninitialized', 'unordered', 'ordered'".to_string(),
+                expected:
+                    "Must be one of 'ORDER_NONE_UNSPECIFIED', 'ORDER_UNORDERED', 'ORDER_ORDERED'"
+                        .to_string(),
                 actual: s.to_string(),
             }),
         }
@@ -562,6 +624,22 @@ impl State {
     pub fn less_or_equal_progress(self, other: Self) -> bool {
         self as u32 <= other as u32
     }
+
+    /// Returns whether a channel in this state may move to the `next` state.
+    ///
+    /// The opening handshake moves a channel from `Uninitialized` to `Init`
+    /// then `Open` on the initiating chain, and from `Uninitialized` to
+    /// `TryOpen` then `Open` on the counterparty chain. A channel in `Init`
+    /// may also move to `TryOpen`, and any initialized channel may be closed.
+    pub fn can_transition_to(self, next: Self) -> bool {
+        matches!(
+            (self, next),
+            (Self::Uninitialized, Self::Init | Self::TryOpen)
+                | (Self::Init, Self::TryOpen | Self::Open)
+                | (Self::TryOpen, Self::Open)
+                | (Self::Init | Self::TryOpen | Self::Open, Self::Closed)
+        )
+    }
 }
 
 /// Provides a `to_string` method.
@@ -571,6 +649,25 @@ impl Display for State {
     }
 }
 
+impl FromStr for State {
+    type Err = ChannelError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "UNINITIALIZED" => Ok(Self::Uninitialized),
+            "INIT" => Ok(Self::Init),
+            "TRYOPEN" => Ok(Self::TryOpen),
+            "OPEN" => Ok(Self::Open),
+            "CLOSED" => Ok(Self::Closed),
+            _ => Err(ChannelError::InvalidState {
+                expected: "Must be one of 'UNINITIALIZED', 'INIT', 'TRYOPEN', 'OPEN', 'CLOSED'"
+                    .to_string(),
+                actual: s.to_string(),
+            }),
+        }
+    }
+}
+
 #[cfg(test)]
 pub mod test_util {
     use crate::core::ics24_host::identifier::{ChannelId, ConnectionId, PortId};
@@ -614,6 +711,8 @@ mod tests {
 
     use crate::core::ics04_channel::channel::test_util::get_dummy_raw_channel_end;
     use crate::core::ics04_channel::channel::ChannelEnd;
+    use crate::core::ics04_channel::error::ChannelError;
+    use crate::core::ics24_host::identifier::{ChannelId, ConnectionId, IdentifierError, PortId};
 
     #[test]
     fn channel_end_try_from_raw() {
@@ -704,6 +803,93 @@ mod tests {
         }
     }
 
+    #[test]
+    fn channel_end_try_from_raw_invalid_connection_hop() {
+        let raw_channel_end = RawChannel {
+            connection_hops: vec![
+                ConnectionId::default().to_string(),
+                "invalid connection id".to_string(),
+            ],
+            ..get_dummy_raw_channel_end(2, Some(0))
+        };
+
+        match ChannelEnd::try_from(raw_channel_end) {
+            Err(ChannelError::InvalidConnectionHop { index, raw, error }) => {
+                assert_eq!(index, 1);
+                assert_eq!(raw, "invalid connection id");
+                assert!(matches!(error, IdentifierError::InvalidCharacter { .. }));
+            }
+            res => panic!("expected an invalid connection hop error, got {res:?}"),
+        }
+    }
+
+    #[test]
+    fn channel_end_counterparty_mut() {
+        let mut channel_end =
+            ChannelEnd::try_from(get_dummy_raw_channel_end(2, None)).expect("valid channel end");
+
+        let port_id = PortId::transfer();
+        let channel_id = ChannelId::new(7);
+
+        let counterparty = channel_end.counterparty_mut();
+        counterparty.port_id = port_id.clone();
+        counterparty.channel_id = Some(channel_id.clone());
+
+        assert_eq!(channel_end.counterparty().port_id(), &port_id);
+        assert_eq!(channel_end.counterparty().channel_id(), Some(&channel_id));
+
+        channel_end.counterparty_mut().channel_id = None;
+
+        assert_eq!(channel_end.counterparty().channel_id(), None);
+    }
+
+    #[test]
+    fn channel_end_connection_hops_within() {
+        let channel_end_with_hops = |hops: u64| {
+            let mut channel_end = ChannelEnd::try_from(get_dummy_raw_channel_end(2, None))
+                .expect("valid channel end");
+            channel_end.connection_hops = (0..hops).map(ConnectionId::new).collect();
+            channel_end
+        };
+
+        assert!(channel_end_with_hops(0)
+            .verify_connection_hops_within(1)
+            .is_err());
+        assert!(channel_end_with_hops(0)
+            .verify_connection_hops_length()
+            .is_err());
+
+        assert!(channel_end_with_hops(1)
+            .verify_connection_hops_within(1)
+            .is_ok());
+        assert!(channel_end_with_hops(1)
+            .verify_connection_hops_length()
+            .is_ok());
+
+        assert!(channel_end_with_hops(3)
+            .verify_connection_hops_within(3)
+            .is_ok());
+        assert!(channel_end_with_hops(3)
+            .verify_connection_hops_within(1)
+            .is_err());
+        assert!(channel_end_with_hops(3)
+            .verify_connection_hops_length()
+            .is_err());
+
+        // Single-hop channels keep reporting the expected length
+        assert!(matches!(
+            channel_end_with_hops(3).verify_connection_hops_length(),
+            Err(ChannelError::InvalidConnectionHopsLength {
+                expected: 1,
+                actual: 3
+            })
+        ));
+        assert!(matches!(
+            channel_end_with_hops(3).verify_connection_hops_within(2),
+            Err(ChannelError::ConnectionHopsLengthOutOfRange { max: 2, actual: 3 })
+        ));
+    }
+
     #[test]
     fn parse_channel_ordering_type() {
         use super::Order;
@@ -748,4 +934,143 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn parse_channel_state() {
+        use super::State;
+
+        let states = [
+            State::Uninitialized,
+            State::Init,
+            State::TryOpen,
+            State::Open,
+            State::Closed,
+        ];
+
+        for state in states {
+            assert_eq!(State::from_str(state.as_string()).unwrap(), state);
+            assert_eq!(
+                State::from_str(&state.as_string().to_lowercase()).unwrap(),
+                state
+            );
+        }
+
+        assert!(State::from_str("UNKNOWN_STATE").is_err());
+    }
+
+    #[test]
+    fn channel_ordering_str_round_trip() {
+        use super::Order;
+
+        for order in [Order::None, Order::Unordered, Order::Ordered] {
+            assert_eq!(Order::from_str(order.as_str()).unwrap(), order);
+        }
+    }
+
+    #[test]
+    fn counterparty_verify_nonempty_channel_id() {
+        use super::Counterparty;
+
+        let counterparty = Counterparty::new(PortId::transfer(), Some(ChannelId::new(7)));
+        assert_eq!(
+            counterparty.verify_nonempty_channel_id().unwrap(),
+            &ChannelId::new(7)
+        );
+
+        let counterparty = Counterparty::new(PortId::transfer(), None);
+        assert!(matches!(
+            counterparty.verify_nonempty_channel_id(),
+            Err(ChannelError::InvalidChannelId { .. })
+        ));
+    }
+
+    #[test]
+    fn channel_state_transitions() {
+        use super::State::{self, *};
+
+        let tests: Vec<(State, State, bool)> = vec![
+            (Uninitialized, Init, true),
+            (Uninitialized, TryOpen, true),
+            (Uninitialized, Open, false),
+            (Uninitialized, Closed, false),
+            (Init, TryOpen, true),
+            (Init, Open, true),
+            (Init, Closed, true),
+            (Init, Init, false),
+            (Init, Uninitialized, false),
+            (TryOpen, Open, true),
+            (TryOpen, Closed, true),
+            (TryOpen, Init, false),
+            (Open, Closed, true),
+            (Open, Init, false),
+            (Open, TryOpen, false),
+            (Open, Open, false),
+            (Closed, Open, false),
+            (Closed, Init, false),
+            (Closed, Closed, false),
+        ];
+
+        for (current, next, want) in tests {
+            assert_eq!(
+                current.can_transition_to(next),
+                want,
+                "transition from {current} to {next}"
+            );
+        }
+    }
+
+    #[test]
+    fn sort_identified_channel_ends() {
+        use super::IdentifiedChannelEnd;
+
+        let channel_end = ChannelEnd::try_from(get_dummy_raw_channel_end(1, None)).unwrap();
+        let identified = |port_id: &str, channel_id: u64| {
+            IdentifiedChannelEnd::new(
+                PortId::from_str(port_id).unwrap(),
+                ChannelId::new(channel_id),
+                channel_end.clone(),
+            )
+        };
+
+        let mut channel_ends = vec![
+            identified("transfer", 1),
+            identified("oracle", 2),
+            identified("transfer", 0),
+            identified("oracle", 0),
+        ];
+        channel_ends.sort();
+
+        assert_eq!(
+            channel_ends,
+            vec![
+                identified("oracle", 0),
+                identified("oracle", 2),
+                identified("transfer", 0),
+                identified("transfer", 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn identified_channel_end_ordering_is_consistent_with_eq() {
+        use super::{IdentifiedChannelEnd, State};
+        use alloc::collections::BTreeSet;
+        use core::cmp::Ordering;
+
+        let identified = |channel_end: ChannelEnd| {
+            IdentifiedChannelEnd::new(PortId::transfer(), ChannelId::new(0), channel_end)
+        };
+
+        let open = identified(ChannelEnd::try_from(get_dummy_raw_channel_end(1, None)).unwrap());
+        let mut closed = open.clone();
+        closed.channel_end.state = State::Closed;
+
+        assert_ne!(open, closed);
+        assert_ne!(open.cmp(&closed), Ordering::Equal);
+
+        // Neither entry is dropped for sharing identifiers with the other
+        let set: BTreeSet<_> = vec![open.clone(), closed.clone()].into_iter().collect();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&open) && set.contains(&closed));
+    }
 }
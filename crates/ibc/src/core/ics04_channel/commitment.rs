@@ -87,6 +87,23 @@ pub(crate) fn compute_packet_commitment(
     timeout_height: &TimeoutHeight,
     timeout_timestamp: &Timestamp,
 ) -> PacketCommitment {
+    hash(packet_commitment_preimage(
+        packet_data,
+        timeout_height,
+        timeout_timestamp,
+    ))
+    .into()
+}
+
+/// Returns the bytes hashed into a packet commitment: the big-endian
+/// encodings of the timeout timestamp in nanoseconds, the timeout revision
+/// number and the timeout revision height, followed by the SHA256 hash of the
+/// packet data.
+fn packet_commitment_preimage(
+    packet_data: &[u8],
+    timeout_height: &TimeoutHeight,
+    timeout_timestamp: &Timestamp,
+) -> Vec<u8> {
     let mut hash_input = timeout_timestamp.nanoseconds().to_be_bytes().to_vec();
 
     let revision_number = timeout_height.commitment_revision_number().to_be_bytes();
@@ -98,7 +115,7 @@ pub(crate) fn compute_packet_commitment(
     let packet_data_hash = hash(packet_data);
     hash_input.append(&mut packet_data_hash.to_vec());
 
-    hash(&hash_input).into()
+    hash_input
 }
 
 /// Compute the commitment for an acknowledgement.
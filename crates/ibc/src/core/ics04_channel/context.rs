@@ -8,6 +8,7 @@ use crate::core::ics24_host::path::{
 };
 use crate::core::{ContextError, ExecutionContext, ValidationContext};
 use crate::prelude::*;
+use core::fmt::Debug;
 use core::time::Duration;
 use num_traits::float::FloatCore;
 
@@ -15,7 +16,7 @@ use crate::core::ics02_client::consensus_state::ConsensusState;
 use crate::core::ics03_connection::connection::ConnectionEnd;
 use crate::core::ics04_channel::channel::ChannelEnd;
 use crate::core::ics04_channel::commitment::PacketCommitment;
-use crate::core::ics24_host::identifier::{ClientId, ConnectionId};
+use crate::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId};
 
 use super::packet::Sequence;
 
@@ -131,6 +132,25 @@ where
     }
 }
 
+/// Generates the identifiers of the channels created on the host chain, which
+/// lets hosts use a scheme other than the sequential `channel-{n}` one.
+pub trait ChannelIdGenerator: Debug {
+    /// Returns the identifier of the channel created when `channel_counter`
+    /// channels already exist. Distinct counters must yield distinct identifiers.
+    fn generate_channel_id(&self, channel_counter: u64) -> ChannelId;
+}
+
+/// The default [`ChannelIdGenerator`], which identifies channels by their
+/// creation order, i.e. `channel-{channel_counter}`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SequentialChannelIdGenerator;
+
+impl ChannelIdGenerator for SequentialChannelIdGenerator {
+    fn generate_channel_id(&self, channel_counter: u64) -> ChannelId {
+        ChannelId::new(channel_counter)
+    }
+}
+
 pub(crate) fn calculate_block_delay(
     delay_period_time: &Duration,
     max_expected_time_per_block: &Duration,
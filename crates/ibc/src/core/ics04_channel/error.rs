@@ -41,6 +41,14 @@ pub enum ChannelError {
     InvalidOrderType { expected: String, actual: String },
     /// invalid connection hops length: expected `{expected}`; actual `{actual}`
     InvalidConnectionHopsLength { expected: usize, actual: usize },
+    /// invalid connection hops length: expected between 1 and `{max}`; actual `{actual}`
+    ConnectionHopsLengthOutOfRange { max: usize, actual: usize },
+    /// invalid connection hop `{raw}` at index `{index}`: `{error}`
+    InvalidConnectionHop {
+        index: usize,
+        raw: String,
+        error: IdentifierError,
+    },
     /// invalid signer error: `{reason}`
     InvalidSigner { reason: String },
     /// invalid proof: missing height
@@ -51,6 +59,10 @@ pub enum ChannelError {
     MissingCounterparty,
     /// version not supported: expected `{expected}`, actual `{actual}`
     VersionNotSupported { expected: Version, actual: Version },
+    /// no common version
+    NoCommonVersion,
+    /// invalid version `{version}`: `{reason}`
+    InvalidVersion { version: Version, reason: String },
     /// missing channel end
     MissingChannel,
     /// the channel end (`{port_id}`, `{channel_id}`) does not exist
@@ -81,6 +93,8 @@ pub enum ChannelError {
     ProcessedHeightNotFound { client_id: ClientId, height: Height },
     /// route not found
     RouteNotFound,
+    /// port `{port_id}` is not bound to any module
+    PortNotBound { port_id: PortId },
     /// application module error: `{description}`
     AppModule { description: String },
     /// other error: `{description}`
@@ -132,6 +146,16 @@ pub enum PacketError {
         timeout_timestamp: Timestamp,
         chain_timestamp: Timestamp,
     },
+    /// Packet timed out: receiving chain height `{chain_height}` > packet timeout height `{timeout_height}`
+    PacketTimeoutHeightElapsed {
+        timeout_height: TimeoutHeight,
+        chain_height: Height,
+    },
+    /// Packet timed out: receiving chain timestamp `{chain_timestamp}` > packet timeout timestamp `{timeout_timestamp}`
+    PacketTimeoutTimestampElapsed {
+        timeout_timestamp: Timestamp,
+        chain_timestamp: Timestamp,
+    },
     /// Packet acknowledgement exists for the packet with the sequence `{sequence}`
     AcknowledgementExists { sequence: Sequence },
     /// Acknowledgment cannot be empty
@@ -218,6 +242,7 @@ impl std::error::Error for ChannelError {
         match &self {
             Self::Port(e) => Some(e),
             Self::InvalidIdentifier(e) => Some(e),
+            Self::InvalidConnectionHop { error: e, .. } => Some(e),
             Self::PacketVerificationFailed {
                 client_error: e, ..
             } => Some(e),
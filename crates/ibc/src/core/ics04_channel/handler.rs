@@ -1,5 +1,9 @@
 //! This module implements the processing logic for ICS4 (channel) messages.
 
+use crate::core::events::{IbcEvent, MessageEvent};
+use crate::core::router::ModuleExtras;
+use crate::core::ExecutionContext;
+
 pub(crate) mod acknowledgement;
 pub(crate) mod chan_close_confirm;
 pub(crate) mod chan_close_init;
@@ -11,3 +15,71 @@ pub(crate) mod recv_packet;
 pub(crate) mod send_packet;
 pub(crate) mod timeout;
 pub(crate) mod timeout_on_close;
+
+/// Emits the channel message event and `core_event`, followed by the events
+/// and logs returned by the module callback. This guarantees that the core
+/// event is always observed before the module events.
+pub(crate) fn emit_with_module_extras<Ctx>(
+    ctx: &mut Ctx,
+    core_event: IbcEvent,
+    extras: ModuleExtras,
+) where
+    Ctx: ExecutionContext,
+{
+    ctx.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel));
+    ctx.emit_ibc_event(core_event);
+
+    for module_event in extras.events {
+        ctx.emit_ibc_event(IbcEvent::Module(module_event));
+    }
+
+    for log_message in extras.log {
+        ctx.log_message(log_message);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    use crate::core::events::ModuleEvent;
+    use crate::core::ics04_channel::events::OpenTry;
+    use crate::core::ics04_channel::Version;
+    use crate::core::ics24_host::identifier::{ChannelId, ConnectionId, PortId};
+    use crate::mock::context::MockContext;
+
+    #[test]
+    fn emit_core_event_before_module_events() {
+        let mut ctx = MockContext::default();
+
+        let core_event = IbcEvent::OpenTryChannel(OpenTry::new(
+            PortId::transfer(),
+            ChannelId::new(1),
+            PortId::transfer(),
+            ChannelId::new(0),
+            ConnectionId::new(0),
+            Version::new("ics20-1".to_string()),
+        ));
+        let module_event = ModuleEvent {
+            kind: "module".to_string(),
+            attributes: vec![("key", "value").into()],
+        };
+        let extras = ModuleExtras {
+            events: vec![module_event.clone()],
+            log: vec!["module log".to_string()],
+        };
+
+        emit_with_module_extras(&mut ctx, core_event.clone(), extras);
+
+        assert_eq!(
+            ctx.events,
+            vec![
+                IbcEvent::Message(MessageEvent::Channel),
+                core_event,
+                IbcEvent::Module(module_event),
+            ]
+        );
+        assert_eq!(ctx.logs, vec!["module log".to_string()]);
+    }
+}
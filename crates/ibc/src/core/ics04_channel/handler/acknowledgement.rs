@@ -28,7 +28,7 @@ where
     validate(ctx_a, &msg)?;
 
     let module = ctx_a
-        .get_route(&module_id)
+        .get_route_or_fallback(&module_id)
         .ok_or(ChannelError::RouteNotFound)?;
 
     module
@@ -74,7 +74,7 @@ where
     };
 
     let module = ctx_a
-        .get_route_mut(&module_id)
+        .get_route_or_fallback_mut(&module_id)
         .ok_or(ChannelError::RouteNotFound)?;
 
     let (extras, cb_result) =
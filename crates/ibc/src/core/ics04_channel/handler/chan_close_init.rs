@@ -1,12 +1,13 @@
 //! Protocol logic specific to ICS4 messages of type `MsgChannelCloseInit`.
 use crate::prelude::*;
 
-use crate::core::events::{IbcEvent, MessageEvent};
+use crate::core::events::IbcEvent;
 use crate::core::ics02_client::client_state::ClientStateCommon;
 use crate::core::ics03_connection::connection::State as ConnectionState;
 use crate::core::ics04_channel::channel::State;
 use crate::core::ics04_channel::error::ChannelError;
 use crate::core::ics04_channel::events::CloseInit;
+use crate::core::ics04_channel::handler::emit_with_module_extras;
 use crate::core::ics04_channel::msgs::chan_close_init::MsgChannelCloseInit;
 use crate::core::ics24_host::path::ChannelEndPath;
 use crate::core::router::ModuleId;
@@ -23,7 +24,7 @@ where
     validate(ctx_a, &msg)?;
 
     let module = ctx_a
-        .get_route(&module_id)
+        .get_route_or_fallback(&module_id)
         .ok_or(ChannelError::RouteNotFound)?;
     module.on_chan_close_init_validate(&msg.port_id_on_a, &msg.chan_id_on_a)?;
 
@@ -39,7 +40,7 @@ where
     ExecCtx: ExecutionContext,
 {
     let module = ctx_a
-        .get_route_mut(&module_id)
+        .get_route_or_fallback_mut(&module_id)
         .ok_or(ChannelError::RouteNotFound)?;
     let extras = module.on_chan_close_init_execute(&msg.port_id_on_a, &msg.chan_id_on_a)?;
     let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
@@ -81,16 +82,7 @@ where
                 conn_id_on_a,
             ))
         };
-        ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel));
-        ctx_a.emit_ibc_event(core_event);
-
-        for module_event in extras.events {
-            ctx_a.emit_ibc_event(IbcEvent::Module(module_event));
-        }
-
-        for log_message in extras.log {
-            ctx_a.log_message(log_message);
-        }
+        emit_with_module_extras(ctx_a, core_event, extras);
     }
 
     Ok(())
@@ -125,6 +117,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::events::MessageEvent;
 
     use crate::core::ics03_connection::connection::ConnectionEnd;
     use crate::core::ics03_connection::connection::Counterparty as ConnectionCounterparty;
@@ -3,7 +3,7 @@
 use crate::prelude::*;
 use ibc_proto::protobuf::Protobuf;
 
-use crate::core::events::{IbcEvent, MessageEvent};
+use crate::core::events::IbcEvent;
 use crate::core::ics02_client::client_state::ClientStateCommon;
 use crate::core::ics02_client::consensus_state::ConsensusState;
 use crate::core::ics03_connection::connection::State as ConnectionState;
@@ -11,6 +11,7 @@ use crate::core::ics04_channel::channel::State;
 use crate::core::ics04_channel::channel::{ChannelEnd, Counterparty, State as ChannelState};
 use crate::core::ics04_channel::error::ChannelError;
 use crate::core::ics04_channel::events::OpenAck;
+use crate::core::ics04_channel::handler::emit_with_module_extras;
 use crate::core::ics04_channel::msgs::chan_open_ack::MsgChannelOpenAck;
 use crate::core::ics24_host::path::Path;
 use crate::core::ics24_host::path::{ChannelEndPath, ClientConsensusStatePath};
@@ -28,7 +29,7 @@ where
     validate(ctx_a, &msg)?;
 
     let module = ctx_a
-        .get_route(&module_id)
+        .get_route_or_fallback(&module_id)
         .ok_or(ChannelError::RouteNotFound)?;
     module.on_chan_open_ack_validate(&msg.port_id_on_a, &msg.chan_id_on_a, &msg.version_on_b)?;
 
@@ -44,7 +45,7 @@ where
     ExecCtx: ExecutionContext,
 {
     let module = ctx_a
-        .get_route_mut(&module_id)
+        .get_route_or_fallback_mut(&module_id)
         .ok_or(ChannelError::RouteNotFound)?;
     let extras =
         module.on_chan_open_ack_execute(&msg.port_id_on_a, &msg.chan_id_on_a, &msg.version_on_b)?;
@@ -52,18 +53,20 @@ where
     let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
 
     // state changes
-    {
+    let chan_end_on_a = {
         let chan_end_on_a = {
-            let mut chan_end_on_a = chan_end_on_a.clone();
+            let mut chan_end_on_a = chan_end_on_a;
 
             chan_end_on_a.set_state(State::Open);
             chan_end_on_a.set_version(msg.version_on_b.clone());
-            chan_end_on_a.set_counterparty_channel_id(msg.chan_id_on_b.clone());
+            chan_end_on_a.set_counterparty_channel_id(msg.chan_id_on_b);
 
             chan_end_on_a
         };
-        ctx_a.store_channel(&chan_end_path_on_a, chan_end_on_a)?;
-    }
+        ctx_a.store_channel(&chan_end_path_on_a, chan_end_on_a.clone())?;
+
+        chan_end_on_a
+    };
 
     // emit events and logs
     {
@@ -71,26 +74,21 @@ where
 
         let core_event = {
             let port_id_on_b = chan_end_on_a.counterparty().port_id.clone();
+            let chan_id_on_b = chan_end_on_a
+                .counterparty()
+                .verify_nonempty_channel_id()?
+                .clone();
             let conn_id_on_a = chan_end_on_a.connection_hops[0].clone();
 
             IbcEvent::OpenAckChannel(OpenAck::new(
                 msg.port_id_on_a.clone(),
                 msg.chan_id_on_a.clone(),
                 port_id_on_b,
-                msg.chan_id_on_b,
+                chan_id_on_b,
                 conn_id_on_a,
             ))
         };
-        ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel));
-        ctx_a.emit_ibc_event(core_event);
-
-        for module_event in extras.events {
-            ctx_a.emit_ibc_event(IbcEvent::Module(module_event));
-        }
-
-        for log_message in extras.log {
-            ctx_a.log_message(log_message);
-        }
+        emit_with_module_extras(ctx_a, core_event, extras);
     }
 
     Ok(())
@@ -165,6 +163,7 @@ where
 mod tests {
 
     use super::*;
+    use crate::core::events::MessageEvent;
     use rstest::*;
     use test_log::test;
 
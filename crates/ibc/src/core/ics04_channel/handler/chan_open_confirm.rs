@@ -3,7 +3,7 @@
 use crate::prelude::*;
 use ibc_proto::protobuf::Protobuf;
 
-use crate::core::events::{IbcEvent, MessageEvent};
+use crate::core::events::IbcEvent;
 use crate::core::ics02_client::client_state::ClientStateCommon;
 use crate::core::ics02_client::consensus_state::ConsensusState;
 use crate::core::ics03_connection::connection::State as ConnectionState;
@@ -11,6 +11,7 @@ use crate::core::ics04_channel::channel::State;
 use crate::core::ics04_channel::channel::{ChannelEnd, Counterparty, State as ChannelState};
 use crate::core::ics04_channel::error::ChannelError;
 use crate::core::ics04_channel::events::OpenConfirm;
+use crate::core::ics04_channel::handler::emit_with_module_extras;
 use crate::core::ics04_channel::msgs::chan_open_confirm::MsgChannelOpenConfirm;
 use crate::core::ics24_host::path::Path;
 use crate::core::ics24_host::path::{ChannelEndPath, ClientConsensusStatePath};
@@ -28,7 +29,7 @@ where
     validate(ctx_b, &msg)?;
 
     let module = ctx_b
-        .get_route(&module_id)
+        .get_route_or_fallback(&module_id)
         .ok_or(ChannelError::RouteNotFound)?;
     module.on_chan_open_confirm_validate(&msg.port_id_on_b, &msg.chan_id_on_b)?;
 
@@ -44,7 +45,7 @@ where
     ExecCtx: ExecutionContext,
 {
     let module = ctx_b
-        .get_route_mut(&module_id)
+        .get_route_or_fallback_mut(&module_id)
         .ok_or(ChannelError::RouteNotFound)?;
 
     let extras = module.on_chan_open_confirm_execute(&msg.port_id_on_b, &msg.chan_id_on_b)?;
@@ -85,16 +86,7 @@ where
             chan_id_on_a,
             conn_id_on_b,
         ));
-        ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel));
-        ctx_b.emit_ibc_event(core_event);
-
-        for module_event in extras.events {
-            ctx_b.emit_ibc_event(IbcEvent::Module(module_event));
-        }
-
-        for log_message in extras.log {
-            ctx_b.log_message(log_message);
-        }
+        emit_with_module_extras(ctx_b, core_event, extras);
     }
 
     Ok(())
@@ -171,6 +163,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::events::MessageEvent;
     use rstest::*;
     use test_log::test;
 
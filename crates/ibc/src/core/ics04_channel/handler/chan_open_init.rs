@@ -2,13 +2,13 @@
 
 use crate::prelude::*;
 
-use crate::core::events::{IbcEvent, MessageEvent};
+use crate::core::events::IbcEvent;
 use crate::core::ics02_client::client_state::ClientStateCommon;
 use crate::core::ics04_channel::channel::{ChannelEnd, Counterparty, State};
 use crate::core::ics04_channel::error::ChannelError;
 use crate::core::ics04_channel::events::OpenInit;
+use crate::core::ics04_channel::handler::emit_with_module_extras;
 use crate::core::ics04_channel::msgs::chan_open_init::MsgChannelOpenInit;
-use crate::core::ics24_host::identifier::ChannelId;
 use crate::core::ics24_host::path::{ChannelEndPath, SeqAckPath, SeqRecvPath, SeqSendPath};
 use crate::core::router::ModuleId;
 use crate::core::{ContextError, ExecutionContext, ValidationContext};
@@ -22,10 +22,12 @@ where
     ValCtx: ValidationContext,
 {
     validate(ctx_a, &msg)?;
-    let chan_id_on_a = ChannelId::new(ctx_a.channel_counter()?);
+    let chan_id_on_a = ctx_a
+        .channel_id_generator()
+        .generate_channel_id(ctx_a.channel_counter()?);
 
     let module = ctx_a
-        .get_route(&module_id)
+        .get_route_or_fallback(&module_id)
         .ok_or(ChannelError::RouteNotFound)?;
     module.on_chan_open_init_validate(
         msg.ordering,
@@ -47,9 +49,11 @@ pub(crate) fn chan_open_init_execute<ExecCtx>(
 where
     ExecCtx: ExecutionContext,
 {
-    let chan_id_on_a = ChannelId::new(ctx_a.channel_counter()?);
+    let chan_id_on_a = ctx_a
+        .channel_id_generator()
+        .generate_channel_id(ctx_a.channel_counter()?);
     let module = ctx_a
-        .get_route_mut(&module_id)
+        .get_route_or_fallback_mut(&module_id)
         .ok_or(ChannelError::RouteNotFound)?;
     let (extras, version) = module.on_chan_open_init_execute(
         msg.ordering,
@@ -99,16 +103,7 @@ where
             conn_id_on_a,
             version,
         ));
-        ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel));
-        ctx_a.emit_ibc_event(core_event);
-
-        for module_event in extras.events {
-            ctx_a.emit_ibc_event(IbcEvent::Module(module_event));
-        }
-
-        for log_message in extras.log {
-            ctx_a.log_message(log_message);
-        }
+        emit_with_module_extras(ctx_a, core_event, extras);
     }
 
     Ok(())
@@ -130,9 +125,7 @@ where
     let client_state_of_b_on_a = ctx_a.client_state(client_id_on_a)?;
     client_state_of_b_on_a.confirm_not_frozen()?;
 
-    let conn_version = conn_end_on_a.versions();
-
-    conn_version[0].verify_feature_supported(msg.ordering.to_string())?;
+    conn_end_on_a.verify_supports_ordering(msg.ordering)?;
 
     Ok(())
 }
@@ -140,6 +133,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::events::MessageEvent;
     use rstest::*;
 
     use crate::clients::ics07_tendermint::client_type as tm_client_type;
@@ -3,7 +3,7 @@
 use crate::prelude::*;
 use ibc_proto::protobuf::Protobuf;
 
-use crate::core::events::{IbcEvent, MessageEvent};
+use crate::core::events::IbcEvent;
 use crate::core::ics02_client::client_state::ClientStateCommon;
 use crate::core::ics02_client::consensus_state::ConsensusState;
 use crate::core::ics03_connection::connection::State as ConnectionState;
@@ -11,8 +11,8 @@ use crate::core::ics04_channel::channel::State;
 use crate::core::ics04_channel::channel::{ChannelEnd, Counterparty, State as ChannelState};
 use crate::core::ics04_channel::error::ChannelError;
 use crate::core::ics04_channel::events::OpenTry;
+use crate::core::ics04_channel::handler::emit_with_module_extras;
 use crate::core::ics04_channel::msgs::chan_open_try::MsgChannelOpenTry;
-use crate::core::ics24_host::identifier::ChannelId;
 use crate::core::ics24_host::path::Path;
 use crate::core::ics24_host::path::{ChannelEndPath, ClientConsensusStatePath};
 use crate::core::ics24_host::path::{SeqAckPath, SeqRecvPath, SeqSendPath};
@@ -29,10 +29,12 @@ where
 {
     validate(ctx_b, &msg)?;
 
-    let chan_id_on_b = ChannelId::new(ctx_b.channel_counter()?);
+    let chan_id_on_b = ctx_b
+        .channel_id_generator()
+        .generate_channel_id(ctx_b.channel_counter()?);
 
     let module = ctx_b
-        .get_route(&module_id)
+        .get_route_or_fallback(&module_id)
         .ok_or(ChannelError::RouteNotFound)?;
     module.on_chan_open_try_validate(
         msg.ordering,
@@ -46,6 +48,26 @@ where
     Ok(())
 }
 
+/// Validates a `MsgChannelOpenTry` against the module bound to
+/// `msg.port_id_on_b`, for hosts that validate the message outside of
+/// [`validate`](crate::core::validate).
+pub fn chan_open_try_validate_routed<ValCtx>(
+    ctx_b: &ValCtx,
+    msg: MsgChannelOpenTry,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    let module_id =
+        ctx_b
+            .lookup_module_by_port(&msg.port_id_on_b)
+            .ok_or(ChannelError::PortNotBound {
+                port_id: msg.port_id_on_b.clone(),
+            })?;
+
+    chan_open_try_validate(ctx_b, module_id, msg)
+}
+
 pub(crate) fn chan_open_try_execute<ExecCtx>(
     ctx_b: &mut ExecCtx,
     module_id: ModuleId,
@@ -54,9 +76,11 @@ pub(crate) fn chan_open_try_execute<ExecCtx>(
 where
     ExecCtx: ExecutionContext,
 {
-    let chan_id_on_b = ChannelId::new(ctx_b.channel_counter()?);
+    let chan_id_on_b = ctx_b
+        .channel_id_generator()
+        .generate_channel_id(ctx_b.channel_counter()?);
     let module = ctx_b
-        .get_route_mut(&module_id)
+        .get_route_or_fallback_mut(&module_id)
         .ok_or(ChannelError::RouteNotFound)?;
 
     let (extras, version) = module.on_chan_open_try_execute(
@@ -109,16 +133,7 @@ where
             conn_id_on_b,
             version,
         ));
-        ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel));
-        ctx_b.emit_ibc_event(core_event);
-
-        for module_event in extras.events {
-            ctx_b.emit_ibc_event(IbcEvent::Module(module_event));
-        }
-
-        for log_message in extras.log {
-            ctx_b.log_message(log_message);
-        }
+        emit_with_module_extras(ctx_b, core_event, extras);
     }
 
     Ok(())
@@ -136,9 +151,7 @@ where
 
     conn_end_on_b.verify_state_matches(&ConnectionState::Open)?;
 
-    let conn_version = conn_end_on_b.versions();
-
-    conn_version[0].verify_feature_supported(msg.ordering.to_string())?;
+    conn_end_on_b.verify_supports_ordering(msg.ordering)?;
 
     // Verify proofs
     {
@@ -191,21 +204,28 @@ mod tests {
     use rstest::*;
     use test_log::test;
 
+    use crate::core::events::{MessageEvent, ModuleEvent};
     use crate::core::ics03_connection::connection::ConnectionEnd;
     use crate::core::ics03_connection::connection::Counterparty as ConnectionCounterparty;
     use crate::core::ics03_connection::connection::State as ConnectionState;
     use crate::core::ics03_connection::msgs::test_util::get_dummy_raw_counterparty;
     use crate::core::ics03_connection::version::get_compatible_versions;
+    use crate::core::ics04_channel::context::ChannelIdGenerator;
     use crate::core::ics04_channel::msgs::chan_open_try::test_util::get_dummy_raw_msg_chan_open_try;
     use crate::core::ics04_channel::msgs::chan_open_try::MsgChannelOpenTry;
-    use crate::core::ics24_host::identifier::{ClientId, ConnectionId};
+    use crate::core::ics04_channel::Version;
+    use crate::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId};
+    use crate::core::router::ModuleExtras;
     use crate::core::timestamp::ZERO_DURATION;
     use crate::Height;
 
     use crate::applications::transfer::MODULE_ID_STR;
-    use crate::mock::client_state::client_type as mock_client_type;
+    use crate::core::ics04_channel::msgs::ChannelMsg;
+    use crate::core::MsgEnvelope;
+    use crate::mock::client_state::{client_type as mock_client_type, MockClientState};
     use crate::mock::context::MockContext;
-    use crate::test_utils::DummyTransferModule;
+    use crate::mock::header::MockHeader;
+    use crate::test_utils::{assert_validate_idempotent, DummyTransferModule};
 
     pub struct Fixture {
         pub ctx: MockContext,
@@ -309,6 +329,111 @@ mod tests {
         assert!(res.is_ok(), "Validation success: happy path")
     }
 
+    #[rstest]
+    fn chan_open_try_validate_routed_bound_port(fixture: Fixture) {
+        let Fixture {
+            ctx,
+            module_id,
+            msg,
+            client_id_on_b,
+            conn_id_on_b,
+            conn_end_on_b,
+            proof_height,
+        } = fixture;
+
+        let mut ctx = ctx
+            .with_client(&client_id_on_b, Height::new(0, proof_height).unwrap())
+            .with_connection(conn_id_on_b, conn_end_on_b);
+        ctx.bind_port(msg.port_id_on_b.clone(), module_id);
+
+        let res = assert_validate_idempotent(
+            &ctx,
+            MsgEnvelope::Channel(ChannelMsg::OpenTry(msg.clone())),
+        );
+        assert!(
+            res.is_ok(),
+            "Validation success: dispatched through the router"
+        );
+
+        let res = chan_open_try_validate_routed(&ctx, msg);
+
+        assert!(res.is_ok(), "Validation success: port bound to a module")
+    }
+
+    #[rstest]
+    fn chan_open_try_validate_routed_unbound_port(fixture: Fixture) {
+        let Fixture {
+            ctx,
+            msg,
+            client_id_on_b,
+            conn_id_on_b,
+            conn_end_on_b,
+            proof_height,
+            ..
+        } = fixture;
+
+        let ctx = ctx
+            .with_client(&client_id_on_b, Height::new(0, proof_height).unwrap())
+            .with_connection(conn_id_on_b, conn_end_on_b);
+        let port_id_on_b = msg.port_id_on_b.clone();
+
+        let res = chan_open_try_validate_routed(&ctx, msg);
+
+        assert!(
+            matches!(
+                res,
+                Err(ContextError::ChannelError(ChannelError::PortNotBound { ref port_id }))
+                    if port_id == &port_id_on_b
+            ),
+            "Validation fails because the port is not bound to a module"
+        )
+    }
+
+    #[rstest]
+    fn chan_open_try_fail_membership_verification(fixture: Fixture) {
+        let Fixture {
+            ctx,
+            msg,
+            client_id_on_b,
+            conn_id_on_b,
+            conn_end_on_b,
+            proof_height,
+            ..
+        } = fixture;
+
+        let client_height = Height::new(0, proof_height).unwrap();
+        let ctx = ctx
+            .with_client(&client_id_on_b, client_height)
+            .with_connection(conn_id_on_b, conn_end_on_b);
+
+        // Only a channel end that the message does not prove is expected.
+        let unexpected_chan_end_path =
+            ChannelEndPath::new(&msg.port_id_on_a, &ChannelId::new(u64::MAX));
+        let client_state = MockClientState::new(MockHeader::new(client_height))
+            .with_expected_membership(vec![(
+                Path::ChannelEnd(unexpected_chan_end_path),
+                Vec::new(),
+            )]);
+        ctx.ibc_store
+            .lock()
+            .clients
+            .get_mut(&client_id_on_b)
+            .expect("client exists")
+            .client_state = Some(client_state.into());
+
+        let res = validate(&ctx, &msg);
+
+        assert!(
+            matches!(
+                res,
+                Err(ContextError::ChannelError(
+                    ChannelError::VerifyChannelFailed(_)
+                ))
+            ),
+            "Validation fails because the channel end proof does not match the expected membership"
+        )
+    }
+
     #[rstest]
     fn chan_open_try_execute_happy_path(fixture: Fixture) {
         let Fixture {
@@ -337,4 +462,128 @@ mod tests {
         ));
         assert!(matches!(ctx.events[1], IbcEvent::OpenTryChannel(_)));
     }
+
+    #[rstest]
+    fn chan_open_try_execute_module_extras(fixture: Fixture) {
+        let Fixture {
+            module_id,
+            msg,
+            client_id_on_b,
+            conn_id_on_b,
+            conn_end_on_b,
+            proof_height,
+            ..
+        } = fixture;
+
+        let module_event = ModuleEvent {
+            kind: "open_try".to_string(),
+            attributes: vec![("key", "value").into()],
+        };
+        let module = DummyTransferModule::new()
+            .with_chan_open_try_version(Version::new("ics20-1".to_string()))
+            .with_chan_open_try_extras(ModuleExtras {
+                events: vec![module_event.clone()],
+                log: vec!["module log".to_string()],
+            });
+
+        let mut ctx = MockContext::default()
+            .with_client(&client_id_on_b, Height::new(0, proof_height).unwrap())
+            .with_connection(conn_id_on_b, conn_end_on_b);
+        ctx.add_route(module_id.clone(), module).unwrap();
+
+        let res = chan_open_try_execute(&mut ctx, module_id, msg);
+
+        assert!(res.is_ok(), "Execution success: happy path");
+
+        assert_eq!(ctx.events.len(), 3);
+        assert!(matches!(
+            ctx.events[0],
+            IbcEvent::Message(MessageEvent::Channel)
+        ));
+        match &ctx.events[1] {
+            IbcEvent::OpenTryChannel(e) => {
+                assert_eq!(e.version_on_b(), &Version::new("ics20-1".to_string()))
+            }
+            e => panic!("expected an OpenTryChannel event, got {e:?}"),
+        }
+        assert_eq!(ctx.events[2], IbcEvent::Module(module_event));
+        assert_eq!(ctx.logs.last(), Some(&"module log".to_string()));
+    }
+
+    #[rstest]
+    fn chan_open_try_execute_stores_module_version(fixture: Fixture) {
+        let Fixture {
+            module_id,
+            mut msg,
+            client_id_on_b,
+            conn_id_on_b,
+            conn_end_on_b,
+            proof_height,
+            ..
+        } = fixture;
+
+        let proposed_version = Version::new("ics20-2".to_string());
+        let chosen_version = Version::new("ics20-1".to_string());
+        msg.version_supported_on_a = proposed_version.clone();
+        let port_id_on_b = msg.port_id_on_b.clone();
+
+        let module = DummyTransferModule::new().with_chan_open_try_version(chosen_version.clone());
+
+        let mut ctx = MockContext::default()
+            .with_client(&client_id_on_b, Height::new(0, proof_height).unwrap())
+            .with_connection(conn_id_on_b, conn_end_on_b);
+        ctx.add_route(module_id.clone(), module).unwrap();
+
+        let res = chan_open_try_execute(&mut ctx, module_id, msg);
+
+        assert!(res.is_ok(), "Execution success: happy path");
+
+        let chan_end_on_b = ctx
+            .channel_end(&ChannelEndPath::new(&port_id_on_b, &ChannelId::new(0)))
+            .unwrap();
+        assert_eq!(chan_end_on_b.version(), &chosen_version);
+        assert_ne!(chan_end_on_b.version(), &proposed_version);
+    }
+
+    #[rstest]
+    fn chan_open_try_execute_custom_channel_id_generator(fixture: Fixture) {
+        #[derive(Debug)]
+        struct OffsetChannelIdGenerator;
+
+        impl ChannelIdGenerator for OffsetChannelIdGenerator {
+            fn generate_channel_id(&self, channel_counter: u64) -> ChannelId {
+                ChannelId::new(1000 + 7 * channel_counter)
+            }
+        }
+
+        let Fixture {
+            ctx,
+            module_id,
+            msg,
+            client_id_on_b,
+            conn_id_on_b,
+            conn_end_on_b,
+            proof_height,
+        } = fixture;
+
+        let port_id_on_b = msg.port_id_on_b.clone();
+        let mut ctx = ctx
+            .with_client(&client_id_on_b, Height::new(0, proof_height).unwrap())
+            .with_connection(conn_id_on_b, conn_end_on_b)
+            .with_channel_id_generator(OffsetChannelIdGenerator);
+
+        let res = chan_open_try_execute(&mut ctx, module_id.clone(), msg.clone());
+        assert!(res.is_ok(), "Execution success: first channel");
+        let res = chan_open_try_execute(&mut ctx, module_id, msg);
+        assert!(res.is_ok(), "Execution success: second channel");
+
+        for chan_id_on_b in [ChannelId::new(1000), ChannelId::new(1007)] {
+            assert!(ctx
+                .channel_end(&ChannelEndPath::new(&port_id_on_b, &chan_id_on_b))
+                .is_ok());
+        }
+        assert!(ctx
+            .channel_end(&ChannelEndPath::new(&port_id_on_b, &ChannelId::new(0)))
+            .is_err());
+    }
 }
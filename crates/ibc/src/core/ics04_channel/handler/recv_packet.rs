@@ -10,6 +10,7 @@ use crate::core::ics04_channel::commitment::{compute_ack_commitment, compute_pac
 use crate::core::ics04_channel::error::ChannelError;
 use crate::core::ics04_channel::error::PacketError;
 use crate::core::ics04_channel::events::{ReceivePacket, WriteAcknowledgement};
+use crate::core::ics04_channel::handler::emit_with_module_extras;
 use crate::core::ics04_channel::msgs::recv_packet::MsgRecvPacket;
 use crate::core::ics04_channel::packet::Receipt;
 use crate::core::ics24_host::path::Path;
@@ -17,7 +18,6 @@ use crate::core::ics24_host::path::{
     AckPath, ChannelEndPath, ClientConsensusStatePath, CommitmentPath, ReceiptPath, SeqRecvPath,
 };
 use crate::core::router::ModuleId;
-use crate::core::timestamp::Expiry;
 use crate::core::{ContextError, ExecutionContext, ValidationContext};
 
 pub(crate) fn recv_packet_validate<ValCtx>(
@@ -75,7 +75,7 @@ where
     }
 
     let module = ctx_b
-        .get_route_mut(&module_id)
+        .get_route_or_fallback_mut(&module_id)
         .ok_or(ChannelError::RouteNotFound)?;
 
     let (extras, acknowledgement) = module.on_recv_packet_execute(&msg.packet, &msg.signer);
@@ -131,16 +131,7 @@ where
             acknowledgement,
             conn_id_on_b.clone(),
         ));
-        ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel));
-        ctx_b.emit_ibc_event(event);
-
-        for module_event in extras.events {
-            ctx_b.emit_ibc_event(IbcEvent::Module(module_event));
-        }
-
-        for log_message in extras.log {
-            ctx_b.log_message(log_message);
-        }
+        emit_with_module_extras(ctx_b, event, extras);
     }
 
     Ok(())
@@ -171,18 +162,9 @@ where
     conn_end_on_b.verify_state_matches(&ConnectionState::Open)?;
 
     let latest_height = ctx_b.host_height()?;
-    if msg.packet.timeout_height_on_b.has_expired(latest_height) {
-        return Err(PacketError::LowPacketHeight {
-            chain_height: latest_height,
-            timeout_height: msg.packet.timeout_height_on_b,
-        }
-        .into());
-    }
-
     let latest_timestamp = ctx_b.host_timestamp()?;
-    if let Expiry::Expired = latest_timestamp.check_expiry(&msg.packet.timeout_timestamp_on_b) {
-        return Err(PacketError::LowPacketTimestamp.into());
-    }
+    msg.packet
+        .verify_not_timed_out(&latest_timestamp, latest_height)?;
 
     // Verify proofs
     {
@@ -293,6 +275,7 @@ mod tests {
     use crate::core::ics04_channel::msgs::recv_packet::test_util::get_dummy_raw_msg_recv_packet;
     use crate::core::ics04_channel::msgs::recv_packet::MsgRecvPacket;
     use crate::core::ics04_channel::packet::Packet;
+    use crate::core::ics04_channel::timeout::TimeoutHeight;
     use crate::core::ics04_channel::Version;
     use crate::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
     use crate::core::timestamp::Timestamp;
@@ -479,6 +462,77 @@ mod tests {
         )
     }
 
+    /// Validates the fixture's message with its packet timeouts replaced by
+    /// the given ones, on a context in which the packet is otherwise valid.
+    fn validate_with_timeouts(
+        fixture: Fixture,
+        timeout_height_on_b: TimeoutHeight,
+        timeout_timestamp_on_b: Timestamp,
+    ) -> Result<(), ContextError> {
+        let Fixture {
+            context,
+            msg,
+            conn_end_on_b,
+            chan_end_on_b,
+            client_height,
+            host_height,
+            ..
+        } = fixture;
+
+        let packet = Packet {
+            timeout_height_on_b,
+            timeout_timestamp_on_b,
+            ..msg.packet.clone()
+        };
+        let context = context
+            .with_client(&ClientId::default(), client_height)
+            .with_connection(ConnectionId::default(), conn_end_on_b)
+            .with_channel(
+                packet.port_id_on_b.clone(),
+                packet.chan_id_on_b.clone(),
+                chan_end_on_b,
+            )
+            .with_height(host_height);
+
+        validate(&context, &MsgRecvPacket { packet, ..msg })
+    }
+
+    #[rstest]
+    fn recv_packet_timeout_height_elapsed(fixture: Fixture) {
+        let timeout_height = fixture.host_height.decrement().unwrap();
+
+        let res = validate_with_timeouts(fixture, timeout_height.into(), Timestamp::none());
+
+        assert!(
+            matches!(
+                res,
+                Err(ContextError::PacketError(
+                    PacketError::PacketTimeoutHeightElapsed { .. }
+                ))
+            ),
+            "expected a timeout height error, got {res:?}"
+        )
+    }
+
+    #[rstest]
+    fn recv_packet_timeout_timestamp_elapsed(fixture: Fixture) {
+        let res = validate_with_timeouts(
+            fixture,
+            TimeoutHeight::Never,
+            Timestamp::from_nanoseconds(1).unwrap(),
+        );
+
+        assert!(
+            matches!(
+                res,
+                Err(ContextError::PacketError(
+                    PacketError::PacketTimeoutTimestampElapsed { .. }
+                ))
+            ),
+            "expected a timeout timestamp error, got {res:?}"
+        )
+    }
+
     #[rstest]
     fn recv_packet_execute_happy_path(fixture: Fixture) {
         let Fixture {
@@ -41,7 +41,7 @@ where
     }?;
 
     let module = ctx_a
-        .get_route(&module_id)
+        .get_route_or_fallback(&module_id)
         .ok_or(ChannelError::RouteNotFound)?;
 
     let (packet, signer) = match timeout_msg_type {
@@ -87,7 +87,7 @@ where
     };
 
     let module = ctx_a
-        .get_route_mut(&module_id)
+        .get_route_or_fallback_mut(&module_id)
         .ok_or(ChannelError::RouteNotFound)?;
 
     let (extras, cb_result) = module.on_timeout_packet_execute(&packet, &signer);
@@ -5,7 +5,7 @@ use ibc_proto::protobuf::Protobuf;
 
 use crate::core::ics04_channel::acknowledgement::Acknowledgement;
 use crate::core::ics04_channel::error::PacketError;
-use crate::core::ics04_channel::packet::Packet;
+use crate::core::ics04_channel::packet::{Packet, Sequence};
 use crate::core::ics23_commitment::commitment::CommitmentProofBytes;
 use crate::core::Msg;
 use crate::signer::Signer;
@@ -27,6 +27,18 @@ pub struct MsgAcknowledgement {
     pub signer: Signer,
 }
 
+impl MsgAcknowledgement {
+    /// Returns the packet being acknowledged.
+    pub fn packet(&self) -> &Packet {
+        &self.packet
+    }
+
+    /// Returns the sequence number of the packet being acknowledged.
+    pub fn sequence(&self) -> Sequence {
+        self.packet.seq_on_a
+    }
+}
+
 impl Msg for MsgAcknowledgement {
     type Raw = RawMsgAcknowledgement;
 
@@ -115,6 +127,8 @@ mod test {
     use crate::core::ics04_channel::error::PacketError;
     use crate::core::ics04_channel::msgs::acknowledgement::test_util::get_dummy_raw_msg_acknowledgement;
     use crate::core::ics04_channel::msgs::acknowledgement::MsgAcknowledgement;
+    use crate::core::ics04_channel::packet::test_utils::get_dummy_raw_packet;
+    use crate::core::ics04_channel::packet::{Packet, Sequence};
     use crate::test_utils::get_dummy_bech32_account;
 
     #[test]
@@ -181,4 +195,27 @@ mod test {
             );
         }
     }
+
+    #[test]
+    fn msg_acknowledgment_rejects_empty_acknowledgement() {
+        let raw = RawMsgAcknowledgement {
+            acknowledgement: Vec::new(),
+            ..get_dummy_raw_msg_acknowledgement(50)
+        };
+
+        assert!(matches!(
+            MsgAcknowledgement::try_from(raw),
+            Err(PacketError::InvalidAcknowledgement)
+        ));
+    }
+
+    #[test]
+    fn msg_acknowledgment_packet_accessors() {
+        let msg = MsgAcknowledgement::try_from(get_dummy_raw_msg_acknowledgement(50)).unwrap();
+
+        let expected_packet = Packet::try_from(get_dummy_raw_packet(50, 1)).unwrap();
+
+        assert_eq!(msg.sequence(), Sequence::from(1));
+        assert_eq!(msg.packet(), &expected_packet);
+    }
 }
@@ -6,6 +6,7 @@ use core::str::FromStr;
 
 use ibc_proto::ibc::core::channel::v1::Packet as RawPacket;
 
+use super::commitment::compute_packet_commitment;
 use super::timeout::TimeoutHeight;
 use crate::core::ics04_channel::error::{ChannelError, PacketError};
 use crate::core::ics24_host::identifier::{ChannelId, PortId};
@@ -35,7 +36,7 @@ pub enum PacketMsgType {
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Receipt {
     Ok,
 }
@@ -192,18 +193,84 @@ impl Packet {
     /// current timestamp `dst_chain_ts` as well as relative to
     /// the height `dst_chain_height`.
     ///
+    /// This is the check performed by the packet handlers: a packet is
+    /// timed-out only once `dst_chain_height` or `dst_chain_ts` is strictly
+    /// past its timeout. At exactly the timeout, it is not yet timed-out
+    /// here, while it already is for [`Packet::is_timed_out`].
+    ///
     /// Note: a timed-out packet should result in a
     /// [`MsgTimeout`](crate::core::ics04_channel::msgs::timeout::MsgTimeout),
     /// instead of the common-case where it results in
     /// [`MsgRecvPacket`](crate::core::ics04_channel::msgs::recv_packet::MsgRecvPacket).
     pub fn timed_out(&self, dst_chain_ts: &Timestamp, dst_chain_height: Height) -> bool {
-        let height_timed_out = self.timeout_height_on_b.has_expired(dst_chain_height);
+        self.verify_not_timed_out(dst_chain_ts, dst_chain_height)
+            .is_err()
+    }
+
+    /// Returns the commitment to this packet that the sending chain stores,
+    /// as specified by ICS-04 and computed by ibc-go: the SHA256 hash of the
+    /// big-endian encodings of the timeout timestamp in nanoseconds, the
+    /// timeout revision number and the timeout revision height, followed by
+    /// the SHA256 hash of the packet data. A timeout that is not set is
+    /// encoded as zero.
+    pub fn commitment_bytes(&self) -> Vec<u8> {
+        compute_packet_commitment(
+            &self.data,
+            &self.timeout_height_on_b,
+            &self.timeout_timestamp_on_b,
+        )
+        .into_vec()
+    }
+
+    /// Checks whether the packet is timed-out at the destination chain height
+    /// `dst_height` and timestamp `dst_timestamp`, following ICS-04: that is,
+    /// if its timeout height is at most `dst_height`, or its timeout
+    /// timestamp is at most `dst_timestamp`.
+    ///
+    /// A timeout that is not set (a zero height or timestamp in the raw
+    /// packet) never elapses. Note that, unlike [`Packet::timed_out`] and the
+    /// packet handlers, a packet is timed-out as soon as the timeout is
+    /// reached, not only once it has been passed. The two helpers therefore
+    /// disagree when the destination chain is exactly at the timeout.
+    pub fn is_timed_out(&self, dst_height: &Height, dst_timestamp: &Timestamp) -> bool {
+        let height_timed_out = match self.timeout_height_on_b {
+            TimeoutHeight::At(timeout_height) => timeout_height <= *dst_height,
+            TimeoutHeight::Never => false,
+        };
 
         let timestamp_timed_out = self.timeout_timestamp_on_b.is_set()
-            && dst_chain_ts.check_expiry(&self.timeout_timestamp_on_b) == Expired;
+            && dst_timestamp.is_set()
+            && self.timeout_timestamp_on_b <= *dst_timestamp;
 
         height_timed_out || timestamp_timed_out
     }
+
+    /// Same as [`Packet::timed_out`], but returns an error telling whether
+    /// the packet timed out by height or by timestamp. The height is checked
+    /// first.
+    pub fn verify_not_timed_out(
+        &self,
+        dst_chain_ts: &Timestamp,
+        dst_chain_height: Height,
+    ) -> Result<(), PacketError> {
+        if self.timeout_height_on_b.has_expired(dst_chain_height) {
+            return Err(PacketError::PacketTimeoutHeightElapsed {
+                timeout_height: self.timeout_height_on_b,
+                chain_height: dst_chain_height,
+            });
+        }
+
+        if self.timeout_timestamp_on_b.is_set()
+            && dst_chain_ts.check_expiry(&self.timeout_timestamp_on_b) == Expired
+        {
+            return Err(PacketError::PacketTimeoutTimestampElapsed {
+                timeout_timestamp: self.timeout_timestamp_on_b,
+                chain_timestamp: *dst_chain_ts,
+            });
+        }
+
+        Ok(())
+    }
 }
 
 /// Custom debug output to omit the packet data
@@ -321,6 +388,9 @@ mod tests {
 
     use crate::core::ics04_channel::packet::test_utils::get_dummy_raw_packet;
     use crate::core::ics04_channel::packet::Packet;
+    use crate::core::ics04_channel::timeout::TimeoutHeight;
+    use crate::core::timestamp::Timestamp;
+    use crate::Height;
 
     #[test]
     fn packet_try_from_raw() {
@@ -493,6 +563,87 @@ mod tests {
         }
     }
 
+    #[test]
+    fn is_timed_out() {
+        let packet = |timeout_height_on_b, timeout_timestamp_on_b| Packet {
+            timeout_height_on_b,
+            timeout_timestamp_on_b,
+            ..Packet::try_from(get_dummy_raw_packet(10, 0)).unwrap()
+        };
+        let height = |revision_height| Height::new(0, revision_height).unwrap();
+        let timestamp = |nanoseconds| Timestamp::from_nanoseconds(nanoseconds).unwrap();
+        let timed_out_at = |packet: &Packet, h, ts| packet.is_timed_out(&height(h), &timestamp(ts));
+
+        // Height only
+        let height_only = packet(TimeoutHeight::At(height(10)), Timestamp::none());
+        assert!(!timed_out_at(&height_only, 9, u64::MAX));
+        assert!(timed_out_at(&height_only, 10, 1));
+        assert!(timed_out_at(&height_only, 11, 1));
+
+        // Timestamp only
+        let timestamp_only = packet(TimeoutHeight::Never, timestamp(1000));
+        assert!(!timed_out_at(&timestamp_only, u64::MAX, 999));
+        assert!(timed_out_at(&timestamp_only, 1, 1000));
+        assert!(timed_out_at(&timestamp_only, 1, 1001));
+
+        // Both, either of which times the packet out
+        let both = packet(TimeoutHeight::At(height(10)), timestamp(1000));
+        assert!(!timed_out_at(&both, 9, 999));
+        assert!(timed_out_at(&both, 10, 999));
+        assert!(timed_out_at(&both, 9, 1000));
+
+        // No timeout
+        let no_timeout = packet(TimeoutHeight::Never, Timestamp::none());
+        assert!(!timed_out_at(&no_timeout, u64::MAX, u64::MAX));
+    }
+
+    #[test]
+    fn timed_out_helpers_at_the_timeout_boundary() {
+        let packet = Packet {
+            timeout_height_on_b: TimeoutHeight::At(Height::new(0, 10).unwrap()),
+            timeout_timestamp_on_b: Timestamp::from_nanoseconds(1000).unwrap(),
+            ..Packet::try_from(get_dummy_raw_packet(10, 0)).unwrap()
+        };
+        let height = |revision_height| Height::new(0, revision_height).unwrap();
+        let timestamp = |nanoseconds| Timestamp::from_nanoseconds(nanoseconds).unwrap();
+
+        // Before the timeout, neither helper considers the packet timed-out
+        assert!(!packet.is_timed_out(&height(9), &timestamp(999)));
+        assert!(!packet.timed_out(&timestamp(999), height(9)));
+
+        // At the timeout height, only `is_timed_out` does
+        assert!(packet.is_timed_out(&height(10), &timestamp(999)));
+        assert!(!packet.timed_out(&timestamp(999), height(10)));
+
+        // At the timeout timestamp, only `is_timed_out` does
+        assert!(packet.is_timed_out(&height(9), &timestamp(1000)));
+        assert!(!packet.timed_out(&timestamp(1000), height(9)));
+
+        // Past the timeout, both do
+        assert!(packet.is_timed_out(&height(11), &timestamp(999)));
+        assert!(packet.timed_out(&timestamp(999), height(11)));
+        assert!(packet.is_timed_out(&height(9), &timestamp(1001)));
+        assert!(packet.timed_out(&timestamp(1001), height(9)));
+    }
+
+    #[test]
+    fn commitment_bytes() {
+        use subtle_encoding::hex;
+
+        let packet = Packet {
+            data: b"hello".to_vec(),
+            timeout_height_on_b: TimeoutHeight::At(Height::new(1, 100).unwrap()),
+            timeout_timestamp_on_b: Timestamp::from_nanoseconds(1_000_000_000).unwrap(),
+            ..Packet::try_from(get_dummy_raw_packet(10, 0)).unwrap()
+        };
+
+        // sha256(be_u64(1_000_000_000) || be_u64(1) || be_u64(100) || sha256("hello"))
+        assert_eq!(
+            hex::encode(packet.commitment_bytes()),
+            b"24c316b418dbed06112fd20ae8eb1c6bd3270d44cbc2902dc540e06cc7edcd4e"
+        );
+    }
+
     #[test]
     fn to_and_from() {
         let raw = get_dummy_raw_packet(15, 0);
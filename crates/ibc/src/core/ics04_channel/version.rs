@@ -57,6 +57,29 @@ impl Version {
         }
         Ok(())
     }
+
+    /// Returns the first of the `supported` versions, in order of preference,
+    /// that is among the versions proposed by the counterparty.
+    pub fn pick_version(
+        supported: &[Version],
+        counterparty_proposed: &[Version],
+    ) -> Result<Version, ChannelError> {
+        supported
+            .iter()
+            .find(|version| counterparty_proposed.contains(version))
+            .cloned()
+            .ok_or(ChannelError::NoCommonVersion)
+    }
+
+    /// Parses the version string as the JSON metadata that application
+    /// versions such as the fee-enabled ICS20 and ICS27 ones are encoded as.
+    #[cfg(feature = "serde")]
+    pub fn as_json(&self) -> Result<serde_json::Value, ChannelError> {
+        serde_json::from_str(&self.0).map_err(|e| ChannelError::InvalidVersion {
+            version: self.clone(),
+            reason: e.to_string(),
+        })
+    }
 }
 
 impl From<String> for Version {
@@ -85,3 +108,59 @@ impl Display for Version {
         write!(f, "{}", self.0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn versions(versions: &[&str]) -> Vec<Version> {
+        versions
+            .iter()
+            .map(|version| Version::new(version.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn pick_version() {
+        let supported = versions(&["ics20-2", "ics20-1"]);
+
+        // The first supported version in order of preference is picked
+        let picked = Version::pick_version(&supported, &versions(&["ics20-1", "ics20-2"])).unwrap();
+        assert_eq!(picked, Version::new("ics20-2".to_string()));
+
+        let picked = Version::pick_version(&supported, &versions(&["ics27-1", "ics20-1"])).unwrap();
+        assert_eq!(picked, Version::new("ics20-1".to_string()));
+
+        // Disjoint sets have no common version
+        assert!(matches!(
+            Version::pick_version(&supported, &versions(&["ics27-1"])),
+            Err(ChannelError::NoCommonVersion)
+        ));
+
+        // Neither have empty sets
+        assert!(matches!(
+            Version::pick_version(&supported, &[]),
+            Err(ChannelError::NoCommonVersion)
+        ));
+        assert!(matches!(
+            Version::pick_version(&[], &supported),
+            Err(ChannelError::NoCommonVersion)
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn as_json() {
+        let version =
+            Version::new(r#"{"fee_version":"ics29-1","app_version":"ics20-1"}"#.to_string());
+        let json = version.as_json().unwrap();
+        assert_eq!(json["fee_version"], "ics29-1");
+        assert_eq!(json["app_version"], "ics20-1");
+
+        // A plain application version is not JSON
+        assert!(matches!(
+            Version::new("ics20-1".to_string()).as_json(),
+            Err(ChannelError::InvalidVersion { .. })
+        ));
+    }
+}
@@ -48,6 +48,17 @@ impl CommitmentRoot {
     pub fn is_empty(&self) -> bool {
         self.bytes.is_empty()
     }
+
+    /// Returns true iff the root is equal to the given bytes.
+    pub fn matches(&self, bytes: &[u8]) -> bool {
+        self.bytes == bytes
+    }
+}
+
+impl PartialEq<[u8]> for CommitmentRoot {
+    fn eq(&self, other: &[u8]) -> bool {
+        self.matches(other)
+    }
 }
 
 impl From<Vec<u8>> for CommitmentRoot {
@@ -151,6 +162,14 @@ pub struct CommitmentPrefix {
 }
 
 impl CommitmentPrefix {
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, CommitmentError> {
+        Self::try_from(bytes.to_vec())
+    }
+
+    pub fn try_from_str(s: &str) -> Result<Self, CommitmentError> {
+        Self::from_bytes(s.as_bytes())
+    }
+
     pub fn as_bytes(&self) -> &[u8] {
         &self.bytes
     }
@@ -207,3 +226,35 @@ pub mod test_util {
         raw_mp.try_into().unwrap()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn commitment_prefix_from_bytes() {
+        assert!(matches!(
+            CommitmentPrefix::from_bytes(&[]),
+            Err(CommitmentError::EmptyCommitmentPrefix)
+        ));
+        assert!(matches!(
+            CommitmentPrefix::try_from_str(""),
+            Err(CommitmentError::EmptyCommitmentPrefix)
+        ));
+
+        let prefix = CommitmentPrefix::try_from_str("ibc").unwrap();
+        assert_eq!(prefix.as_bytes(), b"ibc");
+        assert_eq!(prefix, CommitmentPrefix::from_bytes(b"ibc").unwrap());
+    }
+
+    #[test]
+    fn commitment_root_eq_bytes() {
+        let bytes = [0x1a, 0x2b, 0x3c];
+        let root = CommitmentRoot::from_bytes(&bytes);
+
+        assert!(root.matches(&bytes));
+        assert!(root == bytes[..]);
+        assert!(!root.matches(&bytes[1..]));
+        assert!(root != b"other"[..]);
+    }
+}
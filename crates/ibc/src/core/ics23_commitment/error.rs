@@ -18,12 +18,16 @@ pub enum CommitmentError {
     EmptyMerkleRoot,
     /// empty verified value
     EmptyVerifiedValue,
+    /// proof start index `{start_index}` is out of range for `{len}` proofs
+    InvalidStartIndex { start_index: usize, len: usize },
     /// mismatch between the number of proofs with that of specs
     NumberOfSpecsMismatch,
     /// mismatch between the number of proofs with that of keys
     NumberOfKeysMismatch,
     /// invalid merkle proof
     InvalidMerkleProof,
+    /// expected a non-existence proof but got an existence proof
+    ExpectedNonExistenceProof,
     /// proof verification failed
     VerificationFailure,
     /// encoded commitment prefix is not a valid hex string: `{0}`
@@ -15,12 +15,25 @@ use crate::core::ics23_commitment::commitment::{CommitmentPrefix, CommitmentRoot
 use crate::core::ics23_commitment::error::CommitmentError;
 use crate::core::ics23_commitment::specs::ProofSpecs;
 
+/// Prepends the prefix to the path, rendering the prefix through its `Debug`
+/// implementation. See [`apply_prefix_bytes`] for a variant that decodes the
+/// prefix bytes explicitly.
 pub fn apply_prefix(prefix: &CommitmentPrefix, mut path: Vec<String>) -> MerklePath {
     let mut key_path: Vec<String> = vec![format!("{prefix:?}")];
     key_path.append(&mut path);
     MerklePath { key_path }
 }
 
+/// Prepends the prefix to the path, decoding the prefix bytes as UTF-8.
+///
+/// Unlike [`apply_prefix`], which falls back to a debug rendering of the raw
+/// bytes, invalid UTF-8 sequences are replaced with `U+FFFD`.
+pub fn apply_prefix_bytes(prefix: &CommitmentPrefix, mut path: Vec<String>) -> MerklePath {
+    let mut key_path: Vec<String> = vec![String::from_utf8_lossy(prefix.as_bytes()).into_owned()];
+    key_path.append(&mut path);
+    MerklePath { key_path }
+}
+
 impl From<CommitmentRoot> for MerkleRoot {
     fn from(root: CommitmentRoot) -> Self {
         Self {
@@ -62,6 +75,20 @@ impl MerkleProof {
         value: Vec<u8>,
         start_index: usize,
     ) -> Result<(), CommitmentError> {
+        self.verify_membership_returning_root(specs, root, keys, value, start_index)
+            .map(|_| ())
+    }
+
+    /// Performs the same verification as [`MerkleProof::verify_membership`],
+    /// but returns the root computed from the proofs on success.
+    pub fn verify_membership_returning_root(
+        &self,
+        specs: &ProofSpecs,
+        root: MerkleRoot,
+        keys: MerklePath,
+        value: Vec<u8>,
+        start_index: usize,
+    ) -> Result<Vec<u8>, CommitmentError> {
         // validate arguments
         if self.proofs.is_empty() {
             return Err(CommitmentError::EmptyMerkleProof);
@@ -80,6 +107,12 @@ impl MerkleProof {
         if value.is_empty() {
             return Err(CommitmentError::EmptyVerifiedValue);
         }
+        if start_index >= num {
+            return Err(CommitmentError::InvalidStartIndex {
+                start_index,
+                len: num,
+            });
+        }
 
         let mut subroot = value.clone();
         let mut value = value;
@@ -116,7 +149,7 @@ impl MerkleProof {
             return Err(CommitmentError::VerificationFailure);
         }
 
-        Ok(())
+        Ok(subroot)
     }
 
     pub fn verify_non_membership(
@@ -149,11 +182,7 @@ impl MerkleProof {
         let spec = ics23_specs
             .get(0)
             .ok_or(CommitmentError::InvalidMerkleProof)?;
-        // keys are represented from root-to-leaf
-        let key = keys
-            .key_path
-            .get(num - 1)
-            .ok_or(CommitmentError::InvalidMerkleProof)?;
+        let key = leaf_key(&keys)?;
         match &proof.proof {
             Some(Proof::Nonexist(non_existence_proof)) => {
                 let subroot = calculate_non_existence_root(non_existence_proof)?;
@@ -167,14 +196,31 @@ impl MerkleProof {
                     return Err(CommitmentError::VerificationFailure);
                 }
 
+                // a single non-existence proof must prove against the root directly
+                if num == 1 {
+                    if root.hash != subroot {
+                        return Err(CommitmentError::VerificationFailure);
+                    }
+                    return Ok(());
+                }
+
                 // verify membership proofs starting from index 1 with value = subroot
                 self.verify_membership(specs, root, keys, subroot, 1)
             }
+            Some(Proof::Exist(_)) => Err(CommitmentError::ExpectedNonExistenceProof),
             _ => Err(CommitmentError::InvalidMerkleProof),
         }
     }
 }
 
+/// Returns the key of the lowest subtree, i.e. the last key of `keys` as they
+/// are represented from root-to-leaf.
+fn leaf_key(keys: &MerklePath) -> Result<&String, CommitmentError> {
+    keys.key_path
+        .last()
+        .ok_or(CommitmentError::InvalidMerkleProof)
+}
+
 // TODO move to ics23
 fn calculate_non_existence_root(proof: &NonExistenceProof) -> Result<Vec<u8>, CommitmentError> {
     if let Some(left) = &proof.left {
@@ -187,3 +233,171 @@ fn calculate_non_existence_root(proof: &NonExistenceProof) -> Result<Vec<u8>, Co
         Err(CommitmentError::InvalidMerkleProof)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ibc_proto::ics23::{
+        ExistenceProof, HashOp, InnerSpec, LeafOp, LengthOp, ProofSpec as RawProofSpec,
+    };
+
+    fn leaf_op() -> LeafOp {
+        LeafOp {
+            hash: HashOp::Sha256.into(),
+            prehash_key: HashOp::NoHash.into(),
+            prehash_value: HashOp::NoHash.into(),
+            length: LengthOp::NoPrefix.into(),
+            prefix: vec![0],
+        }
+    }
+
+    /// Specs of a single tree whose root is the hash of its only leaf
+    fn single_leaf_specs() -> ProofSpecs {
+        vec![RawProofSpec {
+            leaf_spec: Some(leaf_op()),
+            inner_spec: Some(InnerSpec {
+                child_order: vec![0, 1],
+                child_size: 32,
+                min_prefix_length: 1,
+                max_prefix_length: 1,
+                empty_child: vec![],
+                hash: HashOp::Sha256.into(),
+            }),
+            max_depth: 0,
+            min_depth: 0,
+            prehash_key_before_comparison: false,
+        }]
+        .into()
+    }
+
+    fn existence_proof(key: &str, value: &[u8]) -> ExistenceProof {
+        ExistenceProof {
+            key: key.as_bytes().to_vec(),
+            value: value.to_vec(),
+            leaf: Some(leaf_op()),
+            path: vec![],
+        }
+    }
+
+    #[test]
+    fn verify_membership_returns_root() {
+        let key = "clients";
+        let value = b"value".to_vec();
+        let existence_proof = existence_proof(key, &value);
+        let root = MerkleRoot {
+            hash: calculate_existence_root::<ics23::HostFunctionsManager>(&existence_proof)
+                .unwrap(),
+        };
+
+        let merkle_proof = MerkleProof {
+            proofs: vec![CommitmentProof {
+                proof: Some(Proof::Exist(existence_proof)),
+            }],
+        };
+        let keys = MerklePath {
+            key_path: vec![key.to_string()],
+        };
+
+        let computed_root = merkle_proof
+            .verify_membership_returning_root(
+                &single_leaf_specs(),
+                root.clone(),
+                keys.clone(),
+                value.clone(),
+                0,
+            )
+            .unwrap();
+        assert_eq!(computed_root, root.hash);
+
+        assert!(merkle_proof
+            .verify_membership(&single_leaf_specs(), root, keys, value, 0)
+            .is_ok());
+    }
+
+    #[test]
+    fn verify_membership_rejects_out_of_range_start_index() {
+        let key = "clients";
+        let value = b"value".to_vec();
+
+        let merkle_proof = MerkleProof {
+            proofs: vec![CommitmentProof {
+                proof: Some(Proof::Exist(existence_proof(key, &value))),
+            }],
+        };
+        let keys = MerklePath {
+            key_path: vec![key.to_string()],
+        };
+        // Skipping every proof would otherwise compare the value to the root
+        let root = MerkleRoot {
+            hash: value.clone(),
+        };
+
+        assert!(matches!(
+            merkle_proof.verify_membership(&single_leaf_specs(), root, keys, value, 1),
+            Err(CommitmentError::InvalidStartIndex {
+                start_index: 1,
+                len: 1
+            })
+        ));
+    }
+
+    #[test]
+    fn verify_non_membership_rejects_existence_proof() {
+        let key = "clients";
+        let existence_proof = existence_proof(key, b"value");
+        let root = MerkleRoot {
+            hash: calculate_existence_root::<ics23::HostFunctionsManager>(&existence_proof)
+                .unwrap(),
+        };
+
+        let merkle_proof = MerkleProof {
+            proofs: vec![CommitmentProof {
+                proof: Some(Proof::Exist(existence_proof)),
+            }],
+        };
+        let keys = MerklePath {
+            key_path: vec![key.to_string()],
+        };
+
+        assert!(matches!(
+            merkle_proof.verify_non_membership(&single_leaf_specs(), root, keys),
+            Err(CommitmentError::ExpectedNonExistenceProof)
+        ));
+    }
+
+    #[test]
+    fn leaf_key_rejects_empty_path() {
+        // `verify_non_membership` rejects empty proofs before looking up the
+        // leaf key, so the guard is exercised directly.
+        let keys = MerklePath { key_path: vec![] };
+        assert!(matches!(
+            leaf_key(&keys),
+            Err(CommitmentError::InvalidMerkleProof)
+        ));
+
+        let keys = MerklePath {
+            key_path: vec!["ibc".to_string(), "clients".to_string()],
+        };
+        assert_eq!(leaf_key(&keys).unwrap(), "clients");
+    }
+
+    #[test]
+    fn apply_prefix_bytes_is_lossy() {
+        let path = vec!["clients".to_string()];
+
+        let prefix = CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap();
+        assert_eq!(
+            apply_prefix_bytes(&prefix, path.clone()),
+            apply_prefix(&prefix, path.clone())
+        );
+
+        let prefix = CommitmentPrefix::try_from(vec![b'i', 0xff, b'c']).unwrap();
+        let merkle_path = apply_prefix_bytes(&prefix, path.clone());
+        assert_eq!(
+            merkle_path.key_path,
+            vec!["i\u{fffd}c".to_string(), "clients".to_string()]
+        );
+        assert_eq!(merkle_path, apply_prefix_bytes(&prefix, path));
+    }
+}
@@ -2,6 +2,7 @@
 
 pub(crate) mod validate;
 use validate::*;
+pub use validate::{channel_id_bounds, client_id_bounds, connection_id_bounds, port_id_bounds};
 
 use core::fmt::{Debug, Display, Error as FmtError, Formatter};
 use core::str::FromStr;
@@ -11,6 +12,7 @@ use displaydoc::Display;
 
 use crate::clients::ics07_tendermint::client_type as tm_client_type;
 use crate::core::ics02_client::client_type::ClientType;
+use crate::core::ics02_client::error::ClientError;
 
 use crate::prelude::*;
 
@@ -83,6 +85,17 @@ impl ChainId {
             .expect("never fails because a valid chain identifier is parsed")
     }
 
+    /// Splits the chain identifier into its chain name and revision number.
+    ///
+    /// ```
+    /// use ibc::core::ics24_host::identifier::ChainId;
+    /// let chain_id = ChainId::new("mockgaiaB", 1).unwrap();
+    /// assert_eq!(chain_id.split(), ("mockgaiaB", 1));
+    /// ```
+    pub fn split(&self) -> (&str, u64) {
+        (self.chain_name(), self.revision_number)
+    }
+
     /// Extract the chain name from the chain identifier
     pub fn chain_name(&self) -> &str {
         self.split_chain_id().0
@@ -106,6 +119,30 @@ impl ChainId {
         self.revision_number = revision_number;
     }
 
+    /// Returns a copy of this `ChainId` with the same chain name and the given
+    /// revision number.
+    /// ```
+    /// use ibc::core::ics24_host::identifier::ChainId;
+    /// let chain_id = ChainId::new("chainA", 1).unwrap();
+    /// assert_eq!(chain_id.with_revision_number(5).as_str(), "chainA-5");
+    /// ```
+    pub fn with_revision_number(&self, revision_number: u64) -> ChainId {
+        let mut chain_id = self.clone();
+        chain_id.set_revision_number(revision_number);
+        chain_id
+    }
+
+    /// Returns a copy of this `ChainId` with the same chain name and the next
+    /// revision number, e.g. to simulate a chain upgrade.
+    /// ```
+    /// use ibc::core::ics24_host::identifier::ChainId;
+    /// let chain_id = ChainId::new("chainA", 1).unwrap();
+    /// assert_eq!(chain_id.increment_revision().as_str(), "chainA-2");
+    /// ```
+    pub fn increment_revision(&self) -> ChainId {
+        self.with_revision_number(self.revision_number + 1)
+    }
+
     /// A convenient method to check if the `ChainId` forms a valid identifier
     /// with the desired min/max length. However, ICS-24 does not specify a
     /// certain min or max lengths for chain identifiers.
@@ -216,6 +253,34 @@ impl ClientId {
     pub fn as_bytes(&self) -> &[u8] {
         self.0.as_bytes()
     }
+
+    /// Decomposes the identifier into its client type and sequence number,
+    /// returning an error if it was not formed as {client type}-{sequence}.
+    ///
+    /// ```
+    /// # use ibc::core::ics24_host::identifier::ClientId;
+    /// # use std::str::FromStr;
+    /// let client_id = ClientId::from_str("07-tendermint-12").unwrap();
+    /// let (client_type, sequence) = client_id.decompose().unwrap();
+    /// assert_eq!(client_type.as_str(), "07-tendermint");
+    /// assert_eq!(sequence, 12);
+    /// ```
+    pub fn decompose(&self) -> Result<(ClientType, u64), ClientError> {
+        let invalid_id = || {
+            ClientError::InvalidClientIdentifier(IdentifierError::InvalidCharacter {
+                id: self.0.clone(),
+            })
+        };
+
+        let (client_type, sequence) = self.0.rsplit_once('-').ok_or_else(invalid_id)?;
+
+        let sequence = sequence.parse::<u64>().map_err(|_| invalid_id())?;
+
+        let client_type =
+            ClientType::new(client_type).map_err(ClientError::InvalidClientIdentifier)?;
+
+        Ok((client_type, sequence))
+    }
 }
 
 /// This implementation provides a `to_string` method.
@@ -542,4 +607,59 @@ mod tests {
         assert!(ChainId::from_str("/chainA-1").is_err());
         assert!(ChainId::from_str("chainA-1-").is_err());
     }
+
+    #[test]
+    fn test_decompose_client_id() {
+        let client_id = ClientId::from_str("07-tendermint-0").unwrap();
+        let (client_type, sequence) = client_id.decompose().unwrap();
+        assert_eq!(client_type, tm_client_type());
+        assert_eq!(sequence, 0);
+
+        let client_id = ClientId::from_str("clientidtwo").unwrap();
+        assert!(client_id.decompose().is_err());
+    }
+
+    #[test]
+    fn test_split_chain_id() {
+        let chain_id = ChainId::new("mockgaiaB", 1).unwrap();
+        assert_eq!(chain_id.split(), ("mockgaiaB", 1));
+
+        let chain_id = ChainId::from_str("chainA-1-2").unwrap();
+        assert_eq!(chain_id.split(), ("chainA-1", 2));
+    }
+
+    #[test]
+    fn test_identifier_length_bounds() {
+        fn assert_bounds<T: FromStr>((min, max): (usize, usize)) {
+            assert!(T::from_str(&"a".repeat(min - 1)).is_err());
+            assert!(T::from_str(&"a".repeat(min)).is_ok());
+            assert!(T::from_str(&"a".repeat(max)).is_ok());
+            assert!(T::from_str(&"a".repeat(max + 1)).is_err());
+        }
+
+        assert_bounds::<ClientId>(client_id_bounds());
+        assert_bounds::<ConnectionId>(connection_id_bounds());
+        assert_bounds::<PortId>(port_id_bounds());
+        assert_bounds::<ChannelId>(channel_id_bounds());
+    }
+
+    #[test]
+    fn test_revision_number_updates() {
+        let chain_id = ChainId::new("name", 0).unwrap();
+
+        let incremented = chain_id.increment_revision();
+        assert_eq!(incremented.as_str(), "name-1");
+        assert_eq!(incremented.split(), ("name", 1));
+        assert_eq!(incremented, ChainId::from_str("name-1").unwrap());
+
+        let bumped = incremented.with_revision_number(7);
+        assert_eq!(bumped.split(), ("name", 7));
+
+        // The original chain identifier is left untouched
+        assert_eq!(chain_id.split(), ("name", 0));
+
+        // Only the trailing revision number is replaced
+        let chain_id = ChainId::from_str("chainA-1-2").unwrap();
+        assert_eq!(chain_id.increment_revision().split(), ("chainA-1", 3));
+    }
 }
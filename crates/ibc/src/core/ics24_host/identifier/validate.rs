@@ -79,6 +79,30 @@ pub fn validate_prefix_length(
     Ok(())
 }
 
+/// Returns the minimum and maximum length of a client identifier, as enforced
+/// by `validate_client_identifier`.
+pub const fn client_id_bounds() -> (usize, usize) {
+    (9, 64)
+}
+
+/// Returns the minimum and maximum length of a connection identifier, as
+/// enforced by `validate_connection_identifier`.
+pub const fn connection_id_bounds() -> (usize, usize) {
+    (10, 64)
+}
+
+/// Returns the minimum and maximum length of a port identifier, as enforced
+/// by `validate_port_identifier`.
+pub const fn port_id_bounds() -> (usize, usize) {
+    (2, 128)
+}
+
+/// Returns the minimum and maximum length of a channel identifier, as
+/// enforced by `validate_channel_identifier`.
+pub const fn channel_id_bounds() -> (usize, usize) {
+    (8, 64)
+}
+
 /// Default validator function for the Client types.
 pub fn validate_client_type(id: &str) -> Result<(), Error> {
     validate_identifier_chars(id)?;
@@ -91,7 +115,8 @@ pub fn validate_client_type(id: &str) -> Result<(), Error> {
 /// the ICS-24 spec.
 pub fn validate_client_identifier(id: &str) -> Result<(), Error> {
     validate_identifier_chars(id)?;
-    validate_identifier_length(id, 9, 64)
+    let (min, max) = client_id_bounds();
+    validate_identifier_length(id, min, max)
 }
 
 /// Default validator function for Connection identifiers.
@@ -100,7 +125,8 @@ pub fn validate_client_identifier(id: &str) -> Result<(), Error> {
 /// in the ICS-24 spec.
 pub fn validate_connection_identifier(id: &str) -> Result<(), Error> {
     validate_identifier_chars(id)?;
-    validate_identifier_length(id, 10, 64)
+    let (min, max) = connection_id_bounds();
+    validate_identifier_length(id, min, max)
 }
 
 /// Default validator function for Port identifiers.
@@ -109,7 +135,8 @@ pub fn validate_connection_identifier(id: &str) -> Result<(), Error> {
 /// ICS-24 spec.
 pub fn validate_port_identifier(id: &str) -> Result<(), Error> {
     validate_identifier_chars(id)?;
-    validate_identifier_length(id, 2, 128)
+    let (min, max) = port_id_bounds();
+    validate_identifier_length(id, min, max)
 }
 
 /// Default validator function for Channel identifiers.
@@ -118,7 +145,8 @@ pub fn validate_port_identifier(id: &str) -> Result<(), Error> {
 /// the ICS-24 spec.
 pub fn validate_channel_identifier(id: &str) -> Result<(), Error> {
     validate_identifier_chars(id)?;
-    validate_identifier_length(id, 8, 64)
+    let (min, max) = channel_id_bounds();
+    validate_identifier_length(id, min, max)
 }
 
 #[cfg(test)]
@@ -45,6 +45,7 @@ pub use context::*;
 pub use msgs::Msg;
 pub use msgs::MsgEnvelope;
 
+pub use ics04_channel::handler::chan_open_try::chan_open_try_validate_routed;
 pub use ics04_channel::handler::send_packet::{
     send_packet, send_packet_execute, send_packet_validate,
 };
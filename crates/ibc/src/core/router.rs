@@ -7,7 +7,6 @@ use core::fmt::{Debug, Display, Error as FmtError, Formatter};
 use crate::core::events::ModuleEvent;
 use crate::core::ics04_channel::acknowledgement::Acknowledgement;
 use crate::core::ics04_channel::channel::{Counterparty, Order};
-use crate::core::ics04_channel::error::PortError::UnknownPort;
 use crate::core::ics04_channel::error::{ChannelError, PacketError};
 use crate::core::ics04_channel::msgs::ChannelMsg;
 use crate::core::ics04_channel::msgs::PacketMsg;
@@ -28,6 +27,34 @@ pub trait Router {
     /// Return the module_id associated with a given port_id
     fn lookup_module_by_port(&self, port_id: &PortId) -> Option<ModuleId>;
 
+    /// Returns a reference to the `Module` that lookups fall back to when no
+    /// module is registered against the requested `ModuleId`. There is no
+    /// fallback module by default.
+    fn fallback_route(&self) -> Option<&dyn Module> {
+        None
+    }
+
+    /// Returns a mutable reference to the fallback `Module`, if any.
+    fn fallback_route_mut(&mut self) -> Option<&mut dyn Module> {
+        None
+    }
+
+    /// Returns a reference to the `Module` registered against the specified
+    /// `ModuleId`, or to the fallback module if there is none.
+    fn get_route_or_fallback(&self, module_id: &ModuleId) -> Option<&dyn Module> {
+        self.get_route(module_id).or_else(|| self.fallback_route())
+    }
+
+    /// Returns a mutable reference to the `Module` registered against the
+    /// specified `ModuleId`, or to the fallback module if there is none.
+    fn get_route_or_fallback_mut(&mut self, module_id: &ModuleId) -> Option<&mut dyn Module> {
+        if self.get_route(module_id).is_some() {
+            self.get_route_mut(module_id)
+        } else {
+            self.fallback_route_mut()
+        }
+    }
+
     fn lookup_module_channel(&self, msg: &ChannelMsg) -> Result<ModuleId, ChannelError> {
         let port_id = match msg {
             ChannelMsg::OpenInit(msg) => &msg.port_id_on_a,
@@ -39,9 +66,9 @@ pub trait Router {
         };
         let module_id = self
             .lookup_module_by_port(port_id)
-            .ok_or(ChannelError::Port(UnknownPort {
+            .ok_or(ChannelError::PortNotBound {
                 port_id: port_id.clone(),
-            }))?;
+            })?;
         Ok(module_id)
     }
 
@@ -54,9 +81,9 @@ pub trait Router {
         };
         let module_id = self
             .lookup_module_by_port(port_id)
-            .ok_or(ChannelError::Port(UnknownPort {
+            .ok_or(ChannelError::PortNotBound {
                 port_id: port_id.clone(),
-            }))?;
+            })?;
         Ok(module_id)
     }
 }
@@ -127,6 +154,24 @@ impl ModuleExtras {
             log: Vec::new(),
         }
     }
+
+    /// Appends an event to the extras.
+    pub fn with_event(mut self, event: ModuleEvent) -> Self {
+        self.events.push(event);
+        self
+    }
+
+    /// Appends a log message to the extras.
+    pub fn with_log(mut self, log: impl ToString) -> Self {
+        self.log.push(log.to_string());
+        self
+    }
+
+    /// Appends the events and logs of `other` after those of `self`.
+    pub fn merge(&mut self, other: ModuleExtras) {
+        self.events.extend(other.events);
+        self.log.extend(other.log);
+    }
 }
 
 /// The trait that defines an IBC application
@@ -278,3 +323,36 @@ pub trait Module: Debug {
         relayer: &Signer,
     ) -> (ModuleExtras, Result<(), PacketError>);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn module_event(kind: &str) -> ModuleEvent {
+        ModuleEvent {
+            kind: kind.to_string(),
+            attributes: vec![("key", kind).into()],
+        }
+    }
+
+    #[test]
+    fn merge_module_extras() {
+        let mut extras = ModuleExtras::empty()
+            .with_event(module_event("first"))
+            .with_log("first log");
+        let other = ModuleExtras::empty()
+            .with_event(module_event("second"))
+            .with_log("second log");
+
+        extras.merge(other);
+
+        assert_eq!(
+            extras.events,
+            vec![module_event("first"), module_event("second")]
+        );
+        assert_eq!(
+            extras.log,
+            vec!["first log".to_string(), "second log".to_string()]
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use crate::prelude::*;
 
+use alloc::sync::Arc;
 use core::str::FromStr;
 use core::time::Duration;
 
@@ -38,10 +39,30 @@ pub fn client_type() -> ClientType {
 /// `ClientState` of ics07_tendermint/client_state.rs.
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MockClientState {
     pub header: MockHeader,
     pub frozen_height: Option<Height>,
+    /// When set, the client is considered expired once the elapsed time
+    /// since its latest consensus state reaches this period. Not part of the
+    /// raw proto message, so it is dropped on encoding.
+    pub trusting_period: Option<Duration>,
+    /// The `(path, value)` pairs that `verify_membership` accepts. When
+    /// empty, any membership proof is accepted.
+    #[cfg_attr(feature = "serde", serde(skip, default = "no_expected_membership"))]
+    pub expected_membership: Arc<[(Path, Vec<u8>)]>,
+    /// The number of consecutive heights, ending at the header height, for
+    /// which `update_state` stores a consensus state, mimicking clients that
+    /// process batched headers.
+    pub update_batch_size: u64,
+    /// When set, `update_state_on_misbehaviour` freezes the client at the
+    /// height of the misbehaviour headers, as real clients do, instead of at
+    /// `Height::min(0)`.
+    pub freeze_at_misbehaviour_height: bool,
+}
+
+fn no_expected_membership() -> Arc<[(Path, Vec<u8>)]> {
+    Arc::from(Vec::new())
 }
 
 impl MockClientState {
@@ -49,6 +70,10 @@ impl MockClientState {
         Self {
             header,
             frozen_height: None,
+            trusting_period: None,
+            expected_membership: no_expected_membership(),
+            update_batch_size: 1,
+            freeze_at_misbehaviour_height: false,
         }
     }
 
@@ -66,6 +91,46 @@ impl MockClientState {
             ..self
         }
     }
+
+    pub fn with_trusting_period(self, trusting_period: Duration) -> Self {
+        Self {
+            trusting_period: Some(trusting_period),
+            ..self
+        }
+    }
+
+    pub fn with_expected_membership(self, expected_membership: Vec<(Path, Vec<u8>)>) -> Self {
+        Self {
+            expected_membership: expected_membership.into(),
+            ..self
+        }
+    }
+
+    pub fn with_update_batch_size(self, update_batch_size: u64) -> Self {
+        Self {
+            update_batch_size,
+            ..self
+        }
+    }
+
+    pub fn with_freeze_at_misbehaviour_height(self, freeze_at_misbehaviour_height: bool) -> Self {
+        Self {
+            freeze_at_misbehaviour_height,
+            ..self
+        }
+    }
+
+    /// Like `confirm_not_frozen`, but only fails if the client is frozen at
+    /// `height` or below, so that operations at lower heights are still
+    /// allowed on a client frozen at a later height.
+    pub fn confirm_not_frozen_at(&self, height: Height) -> Result<(), ClientError> {
+        match self.frozen_height {
+            Some(frozen_height) if height >= frozen_height => Err(ClientError::ClientFrozen {
+                description: format!("The client is frozen at height {frozen_height}"),
+            }),
+            _ => Ok(()),
+        }
+    }
 }
 
 impl Protobuf<RawMockClientState> for MockClientState {}
@@ -74,7 +139,9 @@ impl TryFrom<RawMockClientState> for MockClientState {
     type Error = ClientError;
 
     fn try_from(raw: RawMockClientState) -> Result<Self, Self::Error> {
-        Ok(Self::new(raw.header.expect("Never fails").try_into()?))
+        let raw_header = raw.header.ok_or(ClientError::MissingRawHeader)?;
+
+        Ok(Self::new(raw_header.try_into()?))
     }
 }
 
@@ -159,8 +226,11 @@ impl ClientStateCommon for MockClientState {
         Ok(())
     }
 
-    fn expired(&self, _elapsed: Duration) -> bool {
-        false
+    fn expired(&self, elapsed: Duration) -> bool {
+        match self.trusting_period {
+            Some(trusting_period) => elapsed >= trusting_period,
+            None => false,
+        }
     }
 
     fn verify_upgrade_client(
@@ -187,9 +257,22 @@ impl ClientStateCommon for MockClientState {
         _prefix: &CommitmentPrefix,
         _proof: &CommitmentProofBytes,
         _root: &CommitmentRoot,
-        _path: Path,
-        _value: Vec<u8>,
+        path: Path,
+        value: Vec<u8>,
     ) -> Result<(), ClientError> {
+        if !self.expected_membership.is_empty()
+            && !self
+                .expected_membership
+                .iter()
+                .any(|(expected_path, expected_value)| {
+                    expected_path == &path && expected_value == &value
+                })
+        {
+            return Err(ClientError::Other {
+                description: format!("unexpected membership proof for path `{path}`"),
+            });
+        }
+
         Ok(())
     }
 
@@ -268,7 +351,7 @@ where
     ) -> Result<(), ClientError> {
         let mock_consensus_state = MockConsensusState::try_from(consensus_state)?;
 
-        ctx.store_client_state(ClientStatePath::new(client_id), (*self).into())?;
+        ctx.store_client_state(ClientStatePath::new(client_id), self.clone().into())?;
         ctx.store_consensus_state(
             ClientConsensusStatePath::new(client_id, &self.latest_height()),
             mock_consensus_state.into(),
@@ -286,26 +369,46 @@ where
         let header = MockHeader::try_from(header)?;
         let header_height = header.height;
 
-        let new_client_state = MockClientState::new(header);
-        let new_consensus_state = MockConsensusState::new(header);
+        let mut consensus_heights = vec![header_height];
+        for delta in 1..self.update_batch_size {
+            consensus_heights.push(header_height.sub(delta)?);
+        }
 
-        ctx.store_consensus_state(
-            ClientConsensusStatePath::new(client_id, &new_client_state.latest_height()),
-            new_consensus_state.into(),
-        )?;
+        for consensus_height in consensus_heights.iter() {
+            let new_consensus_state = MockConsensusState::new(
+                MockHeader::new(*consensus_height).with_timestamp(header.timestamp),
+            );
+
+            ctx.store_consensus_state(
+                ClientConsensusStatePath::new(client_id, consensus_height),
+                new_consensus_state.into(),
+            )?;
+        }
+
+        let new_client_state = MockClientState {
+            header,
+            frozen_height: None,
+            ..self.clone()
+        };
         ctx.store_client_state(ClientStatePath::new(client_id), new_client_state.into())?;
 
-        Ok(vec![header_height])
+        Ok(consensus_heights)
     }
 
     fn update_state_on_misbehaviour(
         &self,
         ctx: &mut E,
         client_id: &ClientId,
-        _client_message: Any,
+        client_message: Any,
         _update_kind: &UpdateKind,
     ) -> Result<(), ClientError> {
-        let frozen_client_state = self.with_frozen_height(Height::min(0));
+        let frozen_height = if self.freeze_at_misbehaviour_height {
+            // Both headers are at the same height, see `check_for_misbehaviour`
+            Misbehaviour::try_from(client_message)?.header1.height()
+        } else {
+            Height::min(0)
+        };
+        let frozen_client_state = self.clone().with_frozen_height(frozen_height);
 
         ctx.store_client_state(ClientStatePath::new(client_id), frozen_client_state.into())?;
 
@@ -339,3 +442,95 @@ impl From<MockConsensusState> for MockClientState {
         Self::new(cs.header)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::ValidationContext;
+    use crate::mock::context::{AnyClientState, MockContext};
+
+    #[test]
+    fn expired_honors_trusting_period() {
+        let header = MockHeader::new(Height::new(1, 10).expect("Never fails"));
+
+        let client_state = MockClientState::new(header);
+        assert!(!client_state.expired(Duration::from_secs(u64::MAX)));
+
+        let client_state = client_state.with_trusting_period(Duration::from_secs(10));
+        assert!(!client_state.expired(Duration::from_secs(9)));
+        assert!(client_state.expired(Duration::from_secs(11)));
+    }
+
+    #[test]
+    fn confirm_not_frozen_at_height() {
+        let header = MockHeader::new(Height::new(1, 10).expect("Never fails"));
+        let frozen_height = Height::new(1, 20).expect("Never fails");
+
+        let client_state = MockClientState::new(header);
+        assert!(client_state
+            .confirm_not_frozen_at(Height::new(1, 30).expect("Never fails"))
+            .is_ok());
+
+        let client_state = client_state.with_frozen_height(frozen_height);
+        assert!(client_state.confirm_not_frozen().is_err());
+        assert!(client_state
+            .confirm_not_frozen_at(Height::new(1, 19).expect("Never fails"))
+            .is_ok());
+        assert!(matches!(
+            client_state.confirm_not_frozen_at(frozen_height),
+            Err(ClientError::ClientFrozen { .. })
+        ));
+        assert!(matches!(
+            client_state.confirm_not_frozen_at(Height::new(1, 21).expect("Never fails")),
+            Err(ClientError::ClientFrozen { .. })
+        ));
+    }
+
+    #[test]
+    fn misbehaviour_freezes_at_evidence_height() {
+        let client_id = ClientId::new(mock_client_type(), 0).expect("Never fails");
+        let client_height = Height::new(1, 10).expect("Never fails");
+        let evidence_height = Height::new(1, 15).expect("Never fails");
+
+        let client_state = MockClientState::new(MockHeader::new(client_height))
+            .with_freeze_at_misbehaviour_height(true);
+        let misbehaviour = Misbehaviour {
+            client_id: client_id.clone(),
+            header1: MockHeader::new(evidence_height),
+            header2: MockHeader::new(evidence_height),
+        };
+
+        let mut ctx = MockContext::default();
+        client_state
+            .update_state_on_misbehaviour(
+                &mut ctx,
+                &client_id,
+                misbehaviour.into(),
+                &UpdateKind::SubmitMisbehaviour,
+            )
+            .expect("Never fails");
+
+        let frozen_client_state = match ctx.client_state(&client_id).expect("Never fails") {
+            AnyClientState::Mock(client_state) => client_state,
+            _ => panic!("expected a mock client state"),
+        };
+        assert_eq!(frozen_client_state.frozen_height, Some(evidence_height));
+        assert!(frozen_client_state
+            .confirm_not_frozen_at(client_height)
+            .is_ok());
+        assert!(frozen_client_state
+            .confirm_not_frozen_at(evidence_height)
+            .is_err());
+    }
+
+    #[test]
+    fn try_from_raw_without_header() {
+        let raw = RawMockClientState { header: None };
+
+        assert!(matches!(
+            MockClientState::try_from(raw),
+            Err(ClientError::MissingRawHeader)
+        ));
+    }
+}
@@ -27,6 +27,21 @@ impl MockConsensusState {
         }
     }
 
+    /// Replaces the default commitment root. The root is not part of the raw
+    /// proto message, so it is dropped on encoding.
+    pub fn with_root(self, root: CommitmentRoot) -> Self {
+        Self { root, ..self }
+    }
+
+    /// Pins the timestamp of the consensus state, which otherwise is the one
+    /// of the header it was created from. The height is left unchanged.
+    pub fn with_timestamp(self, timestamp: Timestamp) -> Self {
+        Self {
+            header: self.header.with_timestamp(timestamp),
+            ..self
+        }
+    }
+
     pub fn timestamp(&self) -> Timestamp {
         self.header.timestamp
     }
@@ -107,3 +122,36 @@ impl ConsensusState for MockConsensusState {
         <Self as Protobuf<Any>>::encode_vec(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::Height;
+
+    #[test]
+    fn consensus_state_with_root() {
+        let header = MockHeader::new(Height::new(0, 10).unwrap());
+
+        let consensus_state = MockConsensusState::new(header);
+        assert_eq!(consensus_state.root(), &CommitmentRoot::from(vec![0]));
+
+        let root = CommitmentRoot::from_bytes(b"custom root");
+        let consensus_state = MockConsensusState::new(header).with_root(root.clone());
+        assert_eq!(consensus_state.root(), &root);
+        assert_eq!(consensus_state.header, header);
+    }
+
+    #[test]
+    fn consensus_state_with_timestamp() {
+        let height = Height::new(0, 10).unwrap();
+        let timestamp = Timestamp::from_nanoseconds(1_000_000_000).unwrap();
+
+        let consensus_state = MockConsensusState::new(MockHeader::new(height));
+        assert_ne!(ConsensusState::timestamp(&consensus_state), timestamp);
+
+        let consensus_state = consensus_state.with_timestamp(timestamp);
+        assert_eq!(ConsensusState::timestamp(&consensus_state), timestamp);
+        assert_eq!(consensus_state.header.height(), height);
+    }
+}
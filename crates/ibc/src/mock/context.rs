@@ -5,16 +5,17 @@ mod clients;
 
 use crate::clients::ics07_tendermint::TENDERMINT_CLIENT_TYPE;
 use crate::core::ics24_host::path::{
-    AckPath, ChannelEndPath, ClientConnectionPath, ClientConsensusStatePath, CommitmentPath,
-    ConnectionPath, ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath,
+    AckPath, ChannelEndPath, ClientConnectionPath, ClientConsensusStatePath, ClientStatePath,
+    CommitmentPath, ConnectionPath, ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath,
 };
 use crate::prelude::*;
 
 use alloc::collections::btree_map::BTreeMap;
+use alloc::collections::BTreeSet;
 use alloc::sync::Arc;
 use core::cmp::min;
 use core::fmt::Debug;
-use core::ops::{Add, Sub};
+use core::ops::{Add, Range, Sub};
 use core::time::Duration;
 use derive_more::{From, TryInto};
 use ibc_proto::protobuf::Protobuf;
@@ -24,10 +25,9 @@ use ibc_proto::google::protobuf::Any;
 use tracing::debug;
 
 use crate::clients::ics07_tendermint::client_state::ClientState as TmClientState;
-use crate::clients::ics07_tendermint::client_state::TENDERMINT_CLIENT_STATE_TYPE_URL;
 use crate::clients::ics07_tendermint::consensus_state::ConsensusState as TmConsensusState;
-use crate::clients::ics07_tendermint::consensus_state::TENDERMINT_CONSENSUS_STATE_TYPE_URL;
 
+use crate::applications::transfer::{Amount, PrefixedDenom};
 use crate::core::dispatch;
 use crate::core::events::IbcEvent;
 use crate::core::ics02_client::client_state::ClientState;
@@ -39,6 +39,7 @@ use crate::core::ics03_connection::connection::ConnectionEnd;
 use crate::core::ics03_connection::error::ConnectionError;
 use crate::core::ics04_channel::channel::ChannelEnd;
 use crate::core::ics04_channel::commitment::{AcknowledgementCommitment, PacketCommitment};
+use crate::core::ics04_channel::context::{ChannelIdGenerator, SequentialChannelIdGenerator};
 use crate::core::ics04_channel::error::{ChannelError, PacketError};
 use crate::core::ics04_channel::packet::{Receipt, Sequence};
 use crate::core::ics23_commitment::commitment::CommitmentPrefix;
@@ -57,8 +58,7 @@ use crate::mock::ics18_relayer::error::RelayerError;
 use crate::signer::Signer;
 use crate::Height;
 
-use super::client_state::{MOCK_CLIENT_STATE_TYPE_URL, MOCK_CLIENT_TYPE};
-use super::consensus_state::MOCK_CONSENSUS_STATE_TYPE_URL;
+use super::client_state::MOCK_CLIENT_TYPE;
 
 pub const DEFAULT_BLOCK_TIME_SECS: u64 = 3;
 
@@ -67,41 +67,23 @@ pub const DEFAULT_BLOCK_TIME_SECS: u64 = 3;
            ClientExecutionContext = MockContext)
 ]
 #[mock]
+// The type URLs must be literals, `test_any_type_urls_match_constants` keeps
+// them in sync with the `*_TYPE_URL` constants.
 pub enum AnyClientState {
+    #[type_url = "/ibc.lightclients.tendermint.v1.ClientState"]
     Tendermint(TmClientState),
+    #[type_url = "/ibc.mock.ClientState"]
     Mock(MockClientState),
 }
 
 impl Protobuf<Any> for AnyClientState {}
 
-impl TryFrom<Any> for AnyClientState {
-    type Error = ClientError;
-
-    fn try_from(raw: Any) -> Result<Self, Self::Error> {
-        if raw.type_url == TENDERMINT_CLIENT_STATE_TYPE_URL {
-            TmClientState::try_from(raw).map(Into::into)
-        } else if raw.type_url == MOCK_CLIENT_STATE_TYPE_URL {
-            MockClientState::try_from(raw).map(Into::into)
-        } else {
-            Err(ClientError::Other {
-                description: "failed to deserialize message".to_string(),
-            })
-        }
-    }
-}
-
-impl From<AnyClientState> for Any {
-    fn from(host_client_state: AnyClientState) -> Self {
-        match host_client_state {
-            AnyClientState::Tendermint(cs) => cs.into(),
-            AnyClientState::Mock(cs) => cs.into(),
-        }
-    }
-}
-
 #[derive(Debug, Clone, From, TryInto, PartialEq, ConsensusState)]
+// See `AnyClientState` for the type URLs.
 pub enum AnyConsensusState {
+    #[type_url = "/ibc.lightclients.tendermint.v1.ConsensusState"]
     Tendermint(TmConsensusState),
+    #[type_url = "/ibc.mock.ConsensusState"]
     Mock(MockConsensusState),
 }
 
@@ -111,15 +93,7 @@ impl TryFrom<Any> for AnyConsensusState {
     type Error = ClientError;
 
     fn try_from(raw: Any) -> Result<Self, Self::Error> {
-        if raw.type_url == TENDERMINT_CONSENSUS_STATE_TYPE_URL {
-            TmConsensusState::try_from(raw).map(Into::into)
-        } else if raw.type_url == MOCK_CONSENSUS_STATE_TYPE_URL {
-            MockConsensusState::try_from(raw).map(Into::into)
-        } else {
-            Err(ClientError::Other {
-                description: "failed to deserialize message".to_string(),
-            })
-        }
+        Self::decode_any(raw)
     }
 }
 
@@ -134,7 +108,7 @@ impl From<AnyConsensusState> for Any {
 
 /// A mock of an IBC client record as it is stored in a mock context.
 /// For testing ICS02 handlers mostly, cf. `MockClientContext`.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct MockClientRecord {
     /// The client state (representing only the latest height at the moment).
     pub client_state: Option<AnyClientState>,
@@ -144,7 +118,7 @@ pub struct MockClientRecord {
 }
 
 /// An object that stores all IBC related data.
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct MockIbcStore {
     /// The set of all clients, indexed by their id.
     pub clients: BTreeMap<ClientId, MockClientRecord>,
@@ -196,6 +170,43 @@ pub struct MockIbcStore {
 
     // Used by unordered channel
     pub packet_receipt: PortChannelIdMap<BTreeMap<Sequence, Receipt>>,
+
+    /// Amounts held in escrow by the transfer application, per port, channel and denom
+    pub escrowed_amounts: BTreeMap<(PortId, ChannelId, PrefixedDenom), Amount>,
+
+    /// Total supply of the vouchers minted by the transfer application, per denom
+    pub total_supply: BTreeMap<PrefixedDenom, Amount>,
+}
+
+/// Summary of how many entries of each kind are held in a [`MockIbcStore`],
+/// as returned by [`MockContext::store_stats`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StoreStats {
+    /// Number of clients in the store.
+    pub clients: usize,
+
+    /// Number of consensus states stored for each client.
+    pub consensus_states: BTreeMap<ClientId, usize>,
+
+    /// Number of connections in the store.
+    pub connections: usize,
+
+    /// Number of channels in the store, across all ports.
+    pub channels: usize,
+
+    /// Number of packet commitments in the store, across all channels.
+    pub packet_commitments: usize,
+
+    /// Number of packet receipts in the store, across all channels.
+    pub packet_receipts: usize,
+}
+
+/// A client or consensus state written to the store, as recorded by the
+/// `ClientExecutionContext` store observers of [`MockContext`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum StoreDiff {
+    ClientState(ClientStatePath, AnyClientState),
+    ConsensusState(ClientConsensusStatePath, AnyConsensusState),
 }
 
 /// A context implementing the dependencies necessary for testing any IBC module.
@@ -223,9 +234,30 @@ pub struct MockContext {
     /// To implement ValidationContext Router
     router: BTreeMap<ModuleId, Arc<dyn Module>>,
 
+    /// The module that `Router` lookups fall back to when no exact match exists
+    fallback_route: Option<Arc<dyn Module>>,
+
+    /// Generates the identifiers of the channels opened on this chain
+    channel_id_generator: Arc<dyn ChannelIdGenerator>,
+
+    /// If set, only these accounts are accepted as message signers
+    known_accounts: Option<BTreeSet<Signer>>,
+
+    /// Whether clients delete their expired consensus states when updated
+    pruning_enabled: bool,
+
     pub events: Vec<IbcEvent>,
 
+    /// Every emitted event along with its sequence number, which increases
+    /// monotonically across handler calls. Unlike `events`, this log is never
+    /// cleared.
+    event_log: Vec<(u64, IbcEvent)>,
+
     pub logs: Vec<String>,
+
+    /// The client and consensus states stored so far, in the order the
+    /// handlers reported them.
+    pub store_diffs: Vec<StoreDiff>,
 }
 
 /// Returns a MockContext with bare minimum initialization: no clients, no connections and no channels are
@@ -259,12 +291,41 @@ impl Clone for MockContext {
             block_time: self.block_time,
             ibc_store,
             router: self.router.clone(),
+            fallback_route: self.fallback_route.clone(),
+            channel_id_generator: self.channel_id_generator.clone(),
+            known_accounts: self.known_accounts.clone(),
+            pruning_enabled: self.pruning_enabled,
             events: self.events.clone(),
+            event_log: self.event_log.clone(),
             logs: self.logs.clone(),
+            store_diffs: self.store_diffs.clone(),
         }
     }
 }
 
+/// Compares the host chains, the contents of the `ibc_store`, the emitted
+/// events and logs, and the recorded store diffs. The routes are not
+/// compared, as modules are opaque.
+impl PartialEq for MockContext {
+    fn eq(&self, other: &Self) -> bool {
+        // Locking the same store twice would deadlock.
+        let same_ibc_store = Arc::ptr_eq(&self.ibc_store, &other.ibc_store)
+            || *self.ibc_store.lock() == *other.ibc_store.lock();
+
+        self.host_chain_type == other.host_chain_type
+            && self.host_chain_id == other.host_chain_id
+            && self.max_history_size == other.max_history_size
+            && self.history == other.history
+            && self.block_time == other.block_time
+            && same_ibc_store
+            && self.pruning_enabled == other.pruning_enabled
+            && self.events == other.events
+            && self.event_log == other.event_log
+            && self.logs == other.logs
+            && self.store_diffs == other.store_diffs
+    }
+}
+
 /// Implementation of internal interface for use in testing. The methods in this interface should
 /// _not_ be accessible to any Ics handler.
 impl MockContext {
@@ -322,11 +383,53 @@ impl MockContext {
             block_time,
             ibc_store: Arc::new(Mutex::new(MockIbcStore::default())),
             router: BTreeMap::new(),
+            fallback_route: None,
+            channel_id_generator: Arc::new(SequentialChannelIdGenerator),
+            known_accounts: None,
+            pruning_enabled: false,
             events: Vec::new(),
+            event_log: Vec::new(),
             logs: Vec::new(),
+            store_diffs: Vec::new(),
         }
     }
 
+    /// Creates a default mock context with a mock client `client_id` that holds a
+    /// consensus state for every height in `heights` (on revision 0). The client
+    /// state is at the last height of the range. All the consensus states are
+    /// inserted at once, which makes this cheap to use for benchmark setup.
+    ///
+    /// Panics if `heights` is empty or starts at zero.
+    pub fn prefilled(client_id: &ClientId, heights: Range<u64>) -> Self {
+        assert!(
+            heights.start > 0 && !heights.is_empty(),
+            "heights must be a non-empty range of non-zero heights"
+        );
+        let latest_height = Height::new(0, heights.end - 1).expect("Never fails");
+
+        let consensus_states = heights
+            .map(|h| {
+                let height = Height::new(0, h).expect("Never fails");
+                (
+                    height,
+                    MockConsensusState::new(MockHeader::new(height)).into(),
+                )
+            })
+            .collect();
+
+        let client_record = MockClientRecord {
+            client_state: Some(MockClientState::new(MockHeader::new(latest_height)).into()),
+            consensus_states,
+        };
+
+        let ctx = Self::default();
+        ctx.ibc_store
+            .lock()
+            .clients
+            .insert(client_id.clone(), client_record);
+        ctx
+    }
+
     /// Associates a client record to this context.
     /// Given a client id and a height, registers a new client in the context and also associates
     /// to this client a mock client state and a mock consensus state for height `height`. The type
@@ -335,6 +438,30 @@ impl MockContext {
         self.with_client_parametrized(client_id, height, Some(mock_client_type()), Some(height))
     }
 
+    /// Similar to `with_client`, but the mock client state of the registered client is frozen at
+    /// `frozen_height`.
+    pub fn with_frozen_client(
+        self,
+        client_id: &ClientId,
+        height: Height,
+        frozen_height: Height,
+    ) -> Self {
+        let client_state =
+            MockClientState::new(MockHeader::new(height)).with_frozen_height(frozen_height);
+        let consensus_state: AnyConsensusState =
+            MockConsensusState::new(MockHeader::new(height)).into();
+
+        let client_record = MockClientRecord {
+            client_state: Some(client_state.into()),
+            consensus_states: vec![(height, consensus_state)].into_iter().collect(),
+        };
+        self.ibc_store
+            .lock()
+            .clients
+            .insert(client_id.clone(), client_record);
+        self
+    }
+
     /// Similar to `with_client`, this function associates a client record to this context, but
     /// additionally permits to parametrize two details of the client. If `client_type` is None,
     /// then the client will have type Mock, otherwise the specified type. If
@@ -608,6 +735,39 @@ impl MockContext {
         self
     }
 
+    /// Replaces the sequential generation of channel identifiers with `generator`.
+    pub fn with_channel_id_generator(self, generator: impl ChannelIdGenerator + 'static) -> Self {
+        Self {
+            channel_id_generator: Arc::new(generator),
+            ..self
+        }
+    }
+
+    /// Sets the average time between blocks, which is also reported as the
+    /// maximum expected time per block. Blocks already in the history keep
+    /// their timestamps.
+    pub fn with_block_time(self, block_time: Duration) -> Self {
+        Self { block_time, ..self }
+    }
+
+    /// Makes `validate_message_signer` reject any signer that is not one of
+    /// `accounts`. By default, all signers are accepted.
+    pub fn with_known_accounts(self, accounts: impl IntoIterator<Item = Signer>) -> Self {
+        Self {
+            known_accounts: Some(accounts.into_iter().collect()),
+            ..self
+        }
+    }
+
+    /// Makes clients delete their expired consensus states when they are
+    /// updated. Disabled by default.
+    pub fn with_pruning_enabled(self) -> Self {
+        Self {
+            pruning_enabled: true,
+            ..self
+        }
+    }
+
     pub fn add_route(
         &mut self,
         module_id: ModuleId,
@@ -619,6 +779,12 @@ impl MockContext {
         }
     }
 
+    /// Sets the module that route lookups fall back to when no module is
+    /// registered under the requested `ModuleId`.
+    pub fn set_fallback(&mut self, module: impl Module + 'static) {
+        self.fallback_route = Some(Arc::new(module));
+    }
+
     /// Accessor for a block of the local (host) chain from this context.
     /// Returns `None` if the block at the requested height does not exist.
     pub fn host_block(&self, target_height: &Height) -> Option<&HostBlock> {
@@ -697,19 +863,144 @@ impl MockContext {
 
     pub fn add_port(&mut self, port_id: PortId) {
         let module_id = ModuleId::new(format!("module{port_id}"));
+        self.bind_port(port_id, module_id);
+    }
+
+    /// Binds `port_id` to the module registered under `module_id`, so that
+    /// channel and packet messages on that port are routed to it. Any previous
+    /// binding of the port is replaced.
+    pub fn bind_port(&mut self, port_id: PortId, module_id: ModuleId) {
         self.ibc_store
             .lock()
             .port_to_module
             .insert(port_id, module_id);
     }
 
+    #[deprecated(since = "0.43.0", note = "use `bind_port` instead")]
     pub fn scope_port_to_module(&mut self, port_id: PortId, module_id: ModuleId) {
-        self.ibc_store
-            .lock()
-            .port_to_module
-            .insert(port_id, module_id);
+        self.bind_port(port_id, module_id);
     }
 
+    /// Returns the identifiers of all the clients installed in this context, in ascending order.
+    pub fn client_ids(&self) -> Vec<ClientId> {
+        self.ibc_store.lock().clients.keys().cloned().collect()
+    }
+
+    /// Returns every event emitted so far along with its sequence number, in
+    /// emission order.
+    pub fn events_with_seq(&self) -> &[(u64, IbcEvent)] {
+        &self.event_log
+    }
+
+    /// Returns the number of entries of each kind currently held in the IBC store.
+    pub fn store_stats(&self) -> StoreStats {
+        let ibc_store = self.ibc_store.lock();
+
+        StoreStats {
+            clients: ibc_store.clients.len(),
+            consensus_states: ibc_store
+                .clients
+                .iter()
+                .map(|(client_id, record)| (client_id.clone(), record.consensus_states.len()))
+                .collect(),
+            connections: ibc_store.connections.len(),
+            channels: ibc_store.channels.values().map(BTreeMap::len).sum(),
+            packet_commitments: ibc_store
+                .packet_commitment
+                .values()
+                .flat_map(BTreeMap::values)
+                .map(BTreeMap::len)
+                .sum(),
+            packet_receipts: ibc_store
+                .packet_receipt
+                .values()
+                .flat_map(BTreeMap::values)
+                .map(BTreeMap::len)
+                .sum(),
+        }
+    }
+
+    /// Exports every client in the store along with all of its consensus
+    /// states and the time and height at which each of them was processed,
+    /// ordered by client id and then by height. Clients without a client
+    /// state are skipped.
+    pub fn export_clients(&self) -> Vec<ExportedClient> {
+        let ibc_store = self.ibc_store.lock();
+        ibc_store
+            .clients
+            .iter()
+            .filter_map(|(client_id, record)| {
+                let client_state = record.client_state.clone()?;
+                let consensus_states = record
+                    .consensus_states
+                    .iter()
+                    .map(|(height, cs)| (*height, cs.clone()))
+                    .collect();
+                let processed_times = ibc_store
+                    .client_processed_times
+                    .iter()
+                    .filter(|((id, _), _)| id == client_id)
+                    .map(|((_, height), time)| (*height, *time))
+                    .collect();
+                let processed_heights = ibc_store
+                    .client_processed_heights
+                    .iter()
+                    .filter(|((id, _), _)| id == client_id)
+                    .map(|((_, height), host_height)| (*height, *host_height))
+                    .collect();
+                Some(ExportedClient {
+                    client_id: client_id.clone(),
+                    client_state,
+                    consensus_states,
+                    processed_times,
+                    processed_heights,
+                })
+            })
+            .collect()
+    }
+
+    /// Imports clients previously obtained with [`Self::export_clients`].
+    /// Existing clients with the same identifiers are replaced, and the client
+    /// counter is moved past the imported identifiers so that newly created
+    /// clients do not reuse them.
+    pub fn import_clients(&mut self, clients: impl IntoIterator<Item = ExportedClient>) {
+        let mut ibc_store = self.ibc_store.lock();
+        for client in clients {
+            let ExportedClient {
+                client_id,
+                client_state,
+                consensus_states,
+                processed_times,
+                processed_heights,
+            } = client;
+
+            if let Ok((_, sequence)) = client_id.decompose() {
+                ibc_store.client_ids_counter = ibc_store.client_ids_counter.max(sequence + 1);
+            }
+
+            for (height, time) in processed_times {
+                ibc_store
+                    .client_processed_times
+                    .insert((client_id.clone(), height), time);
+            }
+            for (height, host_height) in processed_heights {
+                ibc_store
+                    .client_processed_heights
+                    .insert((client_id.clone(), height), host_height);
+            }
+
+            let client_record = MockClientRecord {
+                client_state: Some(client_state),
+                consensus_states: consensus_states.into_iter().collect(),
+            };
+            ibc_store.clients.insert(client_id, client_record);
+        }
+    }
+
+    /// Returns the client state stored for `client_id`. This is the same
+    /// state that `ValidationContext::client_state` returns, read directly
+    /// from the store: it panics instead of returning an error if the client
+    /// has no client state.
     pub fn latest_client_states(&self, client_id: &ClientId) -> AnyClientState {
         self.ibc_store.lock().clients[client_id]
             .client_state
@@ -718,6 +1009,20 @@ impl MockContext {
             .clone()
     }
 
+    /// Asserts that the latest height of the client state of `client_id` is
+    /// the highest height at which a consensus state of that client is stored.
+    pub fn assert_client_consistent(&self, client_id: &ClientId) {
+        let client_state = self.latest_client_states(client_id);
+        let consensus_heights =
+            ValidationContext::consensus_state_heights(self, client_id).expect("client exists");
+
+        assert_eq!(
+            consensus_heights.last(),
+            Some(&client_state.latest_height()),
+            "the latest height of client `{client_id}` is not its latest consensus state height"
+        );
+    }
+
     pub fn latest_consensus_states(
         &self,
         client_id: &ClientId,
@@ -749,6 +1054,19 @@ impl MockContext {
 
 type PortChannelIdMap<V> = BTreeMap<PortId, BTreeMap<ChannelId, V>>;
 
+/// A client as exported by [`MockContext::export_clients`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExportedClient {
+    pub client_id: ClientId,
+    pub client_state: AnyClientState,
+    /// The consensus states of the client, ordered by height.
+    pub consensus_states: Vec<(Height, AnyConsensusState)>,
+    /// The host time at which each consensus state height was processed.
+    pub processed_times: Vec<(Height, Timestamp)>,
+    /// The host height at which each consensus state height was processed.
+    pub processed_heights: Vec<(Height, Height)>,
+}
+
 impl RelayerContext for MockContext {
     fn query_latest_height(&self) -> Result<Height, ContextError> {
         ValidationContext::host_height(self)
@@ -789,6 +1107,20 @@ impl Router for MockContext {
     fn lookup_module_by_port(&self, port_id: &PortId) -> Option<ModuleId> {
         self.ibc_store.lock().port_to_module.get(port_id).cloned()
     }
+
+    fn fallback_route(&self) -> Option<&dyn Module> {
+        self.fallback_route.as_ref().map(Arc::as_ref)
+    }
+
+    fn fallback_route_mut(&mut self) -> Option<&mut dyn Module> {
+        match self.fallback_route.as_mut() {
+            Some(arc_mod) => match Arc::get_mut(arc_mod) {
+                Some(m) => Some(m),
+                None => None,
+            },
+            None => None,
+        }
+    }
 }
 
 impl ValidationContext for MockContext {
@@ -850,6 +1182,16 @@ impl ValidationContext for MockContext {
         .map_err(ContextError::ClientError)
     }
 
+    fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError> {
+        match self.ibc_store.lock().clients.get(client_id) {
+            Some(client_record) => Ok(client_record.consensus_states.keys().cloned().collect()),
+            None => Err(ClientError::ClientStateNotFound {
+                client_id: client_id.clone(),
+            }
+            .into()),
+        }
+    }
+
     fn host_height(&self) -> Result<Height, ContextError> {
         Ok(self.latest_height())
     }
@@ -1128,12 +1470,22 @@ impl ValidationContext for MockContext {
         Ok(self.ibc_store.lock().channel_ids_counter)
     }
 
+    fn channel_id_generator(&self) -> &dyn ChannelIdGenerator {
+        self.channel_id_generator.as_ref()
+    }
+
     fn max_expected_time_per_block(&self) -> Duration {
         self.block_time
     }
 
-    fn validate_message_signer(&self, _signer: &Signer) -> Result<(), ContextError> {
-        Ok(())
+    fn validate_message_signer(&self, signer: &Signer) -> Result<(), ContextError> {
+        match &self.known_accounts {
+            Some(accounts) if !accounts.contains(signer) => Err(ClientError::InvalidSigner {
+                reason: format!("unknown account `{signer}`"),
+            }
+            .into()),
+            _ => Ok(()),
+        }
     }
 
     fn get_client_validation_context(&self) -> &Self::ClientValidationContext {
@@ -1304,6 +1656,21 @@ impl ExecutionContext for MockContext {
         Ok(())
     }
 
+    fn store_channels(
+        &mut self,
+        entries: impl IntoIterator<Item = (ChannelEndPath, ChannelEnd)>,
+    ) -> Result<(), ContextError> {
+        let mut ibc_store = self.ibc_store.lock();
+        for (ChannelEndPath(port_id, channel_id), channel_end) in entries {
+            ibc_store
+                .channels
+                .entry(port_id)
+                .or_default()
+                .insert(channel_id, channel_end);
+        }
+        Ok(())
+    }
+
     fn store_next_sequence_send(
         &mut self,
         seq_send_path: &SeqSendPath,
@@ -1355,11 +1722,15 @@ impl ExecutionContext for MockContext {
         Ok(())
     }
 
-    fn increase_channel_counter(&mut self) {
-        self.ibc_store.lock().channel_ids_counter += 1;
+    fn increase_channel_counter(&mut self) -> u64 {
+        let mut ibc_store = self.ibc_store.lock();
+        ibc_store.channel_ids_counter += 1;
+        ibc_store.channel_ids_counter
     }
 
     fn emit_ibc_event(&mut self, event: IbcEvent) {
+        let seq = self.event_log.len() as u64;
+        self.event_log.push((seq, event.clone()));
         self.events.push(event);
     }
 
@@ -1373,18 +1744,30 @@ mod tests {
     use super::*;
     use test_log::test;
 
+    use crate::clients::ics07_tendermint::client_state::TENDERMINT_CLIENT_STATE_TYPE_URL;
+    use crate::clients::ics07_tendermint::consensus_state::TENDERMINT_CONSENSUS_STATE_TYPE_URL;
+    use crate::core::ics02_client::client_state::ClientStateExecution;
+    use crate::core::ics02_client::msgs::create_client::MsgCreateClient;
+    use crate::core::ics02_client::msgs::update_client::MsgUpdateClient;
+    use crate::core::ics02_client::msgs::ClientMsg;
+    use crate::core::ics02_client::ClientExecutionContext;
     use crate::core::ics04_channel::acknowledgement::Acknowledgement;
-    use crate::core::ics04_channel::channel::{Counterparty, Order};
+    use crate::core::ics04_channel::channel::{Counterparty, Order, State};
     use crate::core::ics04_channel::error::ChannelError;
+    use crate::core::ics04_channel::msgs::chan_open_try::test_util::get_dummy_raw_msg_chan_open_try;
+    use crate::core::ics04_channel::msgs::chan_open_try::MsgChannelOpenTry;
+    use crate::core::ics04_channel::msgs::ChannelMsg;
     use crate::core::ics04_channel::packet::Packet;
     use crate::core::ics04_channel::Version;
     use crate::core::ics24_host::identifier::ChainId;
     use crate::core::ics24_host::identifier::{ChannelId, ConnectionId, PortId};
     use crate::core::router::{Module, ModuleExtras, ModuleId};
+    use crate::mock::client_state::MOCK_CLIENT_STATE_TYPE_URL;
+    use crate::mock::consensus_state::MOCK_CONSENSUS_STATE_TYPE_URL;
     use crate::mock::context::MockContext;
     use crate::mock::host::HostType;
     use crate::signer::Signer;
-    use crate::test_utils::get_dummy_bech32_account;
+    use crate::test_utils::{get_dummy_account_id, get_dummy_bech32_account, DummyTransferModule};
     use crate::Height;
 
     #[test]
@@ -1757,4 +2140,516 @@ mod tests {
             on_recv_packet_result("barmodule"),
         ];
     }
+
+    #[test]
+    fn test_router_fallback() {
+        let mut ctx = MockContext::default();
+        ctx.add_route(
+            ModuleId::new("transfer".to_string()),
+            DummyTransferModule::new(),
+        )
+        .expect("Never fails");
+
+        let unknown = ModuleId::new("unknown".to_string());
+        assert!(ctx.get_route_or_fallback(&unknown).is_none());
+        assert!(ctx.get_route_or_fallback_mut(&unknown).is_none());
+
+        let fallback_version = Version::new("fallback".to_string());
+        ctx.set_fallback(
+            DummyTransferModule::new().with_chan_open_try_version(fallback_version.clone()),
+        );
+
+        let on_chan_open_try_version = |ctx: &mut MockContext, module_id: &ModuleId| {
+            ctx.get_route_or_fallback_mut(module_id)
+                .expect("Never fails")
+                .on_chan_open_try_execute(
+                    Order::Unordered,
+                    &[ConnectionId::default()],
+                    &PortId::transfer(),
+                    &ChannelId::default(),
+                    &Counterparty::new(PortId::transfer(), None),
+                    &Version::default(),
+                )
+                .expect("Never fails")
+                .1
+        };
+
+        // Exact matches still take precedence over the fallback
+        let transfer = ModuleId::new("transfer".to_string());
+        assert_eq!(
+            on_chan_open_try_version(&mut ctx, &transfer),
+            Version::default()
+        );
+        // The fallback is only used by the fallback-aware lookups
+        assert!(ctx.get_route(&unknown).is_none());
+        assert!(ctx.get_route_or_fallback(&unknown).is_some());
+        assert_eq!(
+            on_chan_open_try_version(&mut ctx, &unknown),
+            fallback_version
+        );
+    }
+
+    #[test]
+    fn test_bind_port() {
+        let mut ctx = MockContext::default();
+        let module_id = ModuleId::new("transfer".to_string());
+
+        assert_eq!(ctx.lookup_module_by_port(&PortId::transfer()), None);
+
+        ctx.bind_port(PortId::transfer(), module_id.clone());
+        assert_eq!(
+            ctx.lookup_module_by_port(&PortId::transfer()),
+            Some(module_id.clone())
+        );
+
+        // `chan_open_try` resolves its module from the port on chain B
+        let mut msg = MsgChannelOpenTry::try_from(get_dummy_raw_msg_chan_open_try(10)).unwrap();
+        msg.port_id_on_b = PortId::transfer();
+        assert_eq!(
+            ctx.lookup_module_channel(&ChannelMsg::OpenTry(msg))
+                .unwrap(),
+            module_id
+        );
+    }
+
+    #[test]
+    fn test_client_state_any_conversions() {
+        let light_block =
+            HostBlock::generate_tm_block(ChainId::new("mockgaia", 1).unwrap(), 5, Timestamp::now());
+        let client_states: Vec<(AnyClientState, &str)> = vec![
+            (
+                TmClientState::new_dummy_from_header(light_block.header().clone()).into(),
+                TENDERMINT_CLIENT_STATE_TYPE_URL,
+            ),
+            (
+                MockClientState::new(MockHeader::new(Height::new(1, 5).unwrap())).into(),
+                MOCK_CLIENT_STATE_TYPE_URL,
+            ),
+        ];
+
+        for (client_state, type_url) in client_states {
+            let any = Any::from(client_state.clone());
+            assert_eq!(any.type_url, type_url);
+            assert_eq!(AnyClientState::try_from(any).unwrap(), client_state);
+        }
+
+        let unknown = Any {
+            type_url: "/ibc.unknown.ClientState".to_string(),
+            value: Vec::new(),
+        };
+        assert!(matches!(
+            AnyClientState::try_from(unknown),
+            Err(ClientError::UnknownClientStateType { client_state_type })
+                if client_state_type == "/ibc.unknown.ClientState"
+        ));
+    }
+
+    #[test]
+    fn test_any_type_urls_match_constants() {
+        let any_of = |type_url: &str| Any {
+            type_url: type_url.to_string(),
+            value: Vec::new(),
+        };
+
+        for type_url in [TENDERMINT_CLIENT_STATE_TYPE_URL, MOCK_CLIENT_STATE_TYPE_URL] {
+            assert!(AnyClientState::verify_client_state_type(&any_of(type_url)).is_ok());
+        }
+
+        for type_url in [
+            TENDERMINT_CONSENSUS_STATE_TYPE_URL,
+            MOCK_CONSENSUS_STATE_TYPE_URL,
+        ] {
+            assert!(!matches!(
+                AnyConsensusState::decode_any(any_of(type_url)),
+                Err(ClientError::UnknownConsensusStateType { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_verify_client_state_type() {
+        let mock_client_state: AnyClientState =
+            MockClientState::new(MockHeader::new(Height::new(1, 5).unwrap())).into();
+        assert!(AnyClientState::verify_client_state_type(&mock_client_state.into()).is_ok());
+
+        let consensus_state: AnyConsensusState =
+            MockConsensusState::new(MockHeader::new(Height::new(1, 5).unwrap())).into();
+        let wrong_type = Any::from(consensus_state);
+        assert!(matches!(
+            AnyClientState::verify_client_state_type(&wrong_type),
+            Err(ClientError::UnknownClientStateType { client_state_type })
+                if client_state_type == MOCK_CONSENSUS_STATE_TYPE_URL
+        ));
+    }
+
+    #[test]
+    fn test_client_state_derive_generic_context() {
+        use crate::clients::ics07_tendermint::{
+            ExecutionContext as TmExecutionContext, ValidationContext as TmValidationContext,
+        };
+
+        #[derive(Debug, Clone, From, PartialEq, ClientState)]
+        #[generics(ClientValidationContext = Ctx,
+                   ClientExecutionContext = Ctx,
+                   generic = "Ctx: TmValidationContext + TmExecutionContext")
+        ]
+        #[mock]
+        enum GenericClientState {
+            Tendermint(TmClientState),
+            Mock(MockClientState),
+        }
+
+        fn latest_height<Ctx>(client_state: &impl ClientState<Ctx, Ctx>) -> Height
+        where
+            Ctx: TmValidationContext + TmExecutionContext,
+        {
+            client_state.latest_height()
+        }
+
+        let height = Height::new(1, 5).unwrap();
+        let light_block = HostBlock::generate_tm_block(
+            ChainId::new("mockgaia", 1).unwrap(),
+            height.revision_height(),
+            Timestamp::now(),
+        );
+        let client_states = vec![
+            GenericClientState::from(TmClientState::new_dummy_from_header(
+                light_block.header().clone(),
+            )),
+            GenericClientState::from(MockClientState::new(MockHeader::new(height))),
+        ];
+
+        for client_state in client_states {
+            assert_eq!(latest_height::<MockContext>(&client_state), height);
+        }
+    }
+
+    #[test]
+    fn test_consensus_state_decode_any() {
+        let timestamp = Timestamp::now();
+        let consensus_states: Vec<(AnyConsensusState, &str)> = vec![
+            (
+                HostBlock::generate_block(
+                    ChainId::new("mockgaia", 1).unwrap(),
+                    HostType::SyntheticTendermint,
+                    5,
+                    timestamp,
+                )
+                .into(),
+                TENDERMINT_CONSENSUS_STATE_TYPE_URL,
+            ),
+            (
+                MockConsensusState::new(MockHeader::new(Height::new(1, 5).unwrap())).into(),
+                MOCK_CONSENSUS_STATE_TYPE_URL,
+            ),
+        ];
+
+        for (consensus_state, type_url) in consensus_states {
+            let any = Any::from(consensus_state.clone());
+            assert_eq!(any.type_url, type_url);
+            assert_eq!(AnyConsensusState::decode_any(any).unwrap(), consensus_state);
+        }
+
+        let unknown = Any {
+            type_url: "/ibc.unknown.ConsensusState".to_string(),
+            value: Vec::new(),
+        };
+        assert!(matches!(
+            AnyConsensusState::decode_any(unknown),
+            Err(ClientError::UnknownConsensusStateType { consensus_state_type })
+                if consensus_state_type == "/ibc.unknown.ConsensusState"
+        ));
+    }
+
+    #[test]
+    fn test_increase_channel_counter() {
+        let mut ctx = MockContext::default();
+        assert_eq!(ctx.channel_counter().unwrap(), 0);
+
+        assert_eq!(ctx.increase_channel_counter(), 1);
+        assert_eq!(ctx.increase_channel_counter(), 2);
+        assert_eq!(ctx.channel_counter().unwrap(), 2);
+    }
+
+    #[test]
+    fn test_clone_and_eq() {
+        let ctx =
+            MockContext::default().with_client(&ClientId::default(), Height::new(0, 5).unwrap());
+
+        let mut snapshot = ctx.clone();
+        assert_eq!(ctx, snapshot);
+
+        // The clone does not share its store with the original context
+        snapshot.increase_channel_counter();
+        assert_ne!(ctx, snapshot);
+        assert_eq!(ctx.channel_counter().unwrap(), 0);
+
+        let mut snapshot = ctx.clone();
+        snapshot.log_message("log".to_string());
+        assert_ne!(ctx, snapshot);
+    }
+
+    #[test]
+    fn test_store_stats() {
+        let client_id = ClientId::default();
+        let port_id = PortId::transfer();
+        let chan_id = ChannelId::default();
+
+        let channel_end = ChannelEnd::new(
+            State::Open,
+            Order::Unordered,
+            Counterparty::new(PortId::transfer(), Some(ChannelId::default())),
+            vec![ConnectionId::default()],
+            Version::new("ics20-1".to_string()),
+        )
+        .unwrap();
+
+        let ctx = MockContext::default()
+            .with_client(&client_id, Height::new(0, 5).unwrap())
+            .with_channel(port_id.clone(), chan_id.clone(), channel_end)
+            .with_packet_commitment(port_id, chan_id, 1.into(), vec![0].into());
+
+        let stats = ctx.store_stats();
+        assert_eq!(stats.clients, 1);
+        assert_eq!(stats.consensus_states.get(&client_id), Some(&1));
+        assert_eq!(stats.connections, 0);
+        assert_eq!(stats.channels, 1);
+        assert_eq!(stats.packet_commitments, 1);
+        assert_eq!(stats.packet_receipts, 0);
+
+        assert_eq!(MockContext::default().store_stats(), StoreStats::default());
+    }
+
+    #[test]
+    fn test_store_channels() {
+        let channel_end = ChannelEnd::new(
+            State::Open,
+            Order::Unordered,
+            Counterparty::new(PortId::transfer(), Some(ChannelId::default())),
+            vec![ConnectionId::default()],
+            Version::new("ics20-1".to_string()),
+        )
+        .unwrap();
+        let paths: Vec<ChannelEndPath> = (0..3)
+            .map(|i| ChannelEndPath::new(&PortId::transfer(), &ChannelId::new(i)))
+            .collect();
+
+        let mut ctx = MockContext::default();
+        ctx.store_channels(paths.iter().map(|path| (path.clone(), channel_end.clone())))
+            .unwrap();
+
+        assert_eq!(ctx.store_stats().channels, 3);
+        for path in &paths {
+            assert_eq!(ctx.channel_end(path).unwrap(), channel_end);
+        }
+    }
+
+    #[test]
+    fn test_export_import_clients() {
+        let mut ctx = MockContext::default();
+        for height in [5, 7] {
+            let header = MockHeader::new(Height::new(0, height).unwrap());
+            ctx.deliver(MsgEnvelope::Client(ClientMsg::CreateClient(
+                MsgCreateClient::new(
+                    MockClientState::new(header).into(),
+                    MockConsensusState::new(header).into(),
+                    get_dummy_account_id(),
+                ),
+            )))
+            .unwrap();
+        }
+
+        let client_id_0 = ClientId::new(mock_client_type(), 0).unwrap();
+        ctx.deliver(MsgEnvelope::Client(ClientMsg::UpdateClient(
+            MsgUpdateClient {
+                client_id: client_id_0.clone(),
+                header: MockHeader::new(Height::new(0, 8).unwrap()).into(),
+                signer: get_dummy_account_id(),
+            },
+        )))
+        .unwrap();
+
+        let exported = ctx.export_clients();
+        assert_eq!(exported.len(), 2);
+        assert_eq!(exported[0].consensus_states.len(), 2);
+        assert_eq!(exported[0].processed_times.len(), 2);
+        assert_eq!(exported[0].processed_heights.len(), 2);
+
+        let mut imported = MockContext::default();
+        imported.import_clients(exported.clone());
+
+        assert_eq!(imported.export_clients(), exported);
+        assert_eq!(*imported.ibc_store.lock(), *ctx.ibc_store.lock());
+
+        // Newly created clients do not reuse the imported identifiers
+        assert_eq!(imported.client_counter().unwrap(), 2);
+        assert!(ValidationContext::client_update_time(
+            &imported,
+            &client_id_0,
+            &Height::new(0, 8).unwrap()
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_known_accounts() {
+        let alice = Signer::from("alice".to_string());
+        let bob = Signer::from("bob".to_string());
+
+        let ctx = MockContext::default();
+        assert!(ctx.validate_message_signer(&bob).is_ok());
+
+        let ctx = ctx.with_known_accounts([alice.clone()]);
+        assert!(ctx.validate_message_signer(&alice).is_ok());
+        assert!(matches!(
+            ctx.validate_message_signer(&bob),
+            Err(ContextError::ClientError(ClientError::InvalidSigner { .. }))
+        ));
+    }
+
+    #[test]
+    fn test_consensus_state_heights() {
+        let client_id = ClientId::default();
+        let height = Height::new(0, 10).unwrap();
+
+        let ctx = MockContext::default().with_client_parametrized_history(
+            &client_id,
+            height,
+            Some(mock_client_type()),
+            Some(height),
+        );
+
+        assert_eq!(
+            ctx.consensus_state_heights(&client_id).unwrap(),
+            vec![Height::new(0, 9).unwrap(), height]
+        );
+        assert!(ctx
+            .consensus_state_heights(&ClientId::new(mock_client_type(), 1).unwrap())
+            .is_err());
+    }
+
+    #[test]
+    fn test_assert_client_consistent() {
+        let client_id = ClientId::default();
+        let client_height = Height::new(0, 10).unwrap();
+        let update_height = Height::new(0, 15).unwrap();
+
+        let mut ctx = MockContext::default().with_client(&client_id, client_height);
+        ctx.assert_client_consistent(&client_id);
+
+        let client_state =
+            MockClientState::new(MockHeader::new(client_height)).with_update_batch_size(3);
+        client_state
+            .update_state(&mut ctx, &client_id, MockHeader::new(update_height).into())
+            .unwrap();
+
+        ctx.assert_client_consistent(&client_id);
+        assert_eq!(
+            ctx.latest_client_states(&client_id).latest_height(),
+            update_height
+        );
+    }
+
+    #[test]
+    fn test_with_block_time() {
+        let ctx = MockContext::default().with_block_time(Duration::from_secs(7));
+
+        assert_eq!(ctx.max_expected_time_per_block(), Duration::from_secs(7));
+        assert_eq!(ctx.block_delay(&Duration::from_secs(20)), 3);
+    }
+
+    #[test]
+    fn test_delete_consensus_state() {
+        let client_id = ClientId::default();
+        let height = Height::new(0, 5).unwrap();
+        let path = ClientConsensusStatePath::new(&client_id, &height);
+
+        let mut ctx = MockContext::default().with_client(&client_id, height);
+        let consensus_state = ctx.consensus_state(&path).unwrap();
+
+        ctx.delete_consensus_state(path.clone()).unwrap();
+        assert!(ctx.consensus_state(&path).is_err());
+
+        ctx.store_consensus_state(path.clone(), consensus_state)
+            .unwrap();
+        assert!(ctx.consensus_state(&path).is_ok());
+    }
+
+    #[test]
+    fn test_prefilled() {
+        let client_id = ClientId::default();
+
+        let ctx = MockContext::prefilled(&client_id, 1..101);
+
+        assert_eq!(
+            ctx.store_stats().consensus_states.get(&client_id),
+            Some(&100)
+        );
+        assert_eq!(
+            ctx.client_state(&client_id).unwrap().latest_height(),
+            Height::new(0, 100).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_with_frozen_client() {
+        let client_id = ClientId::default();
+        let height = Height::new(0, 42).unwrap();
+
+        let ctx = MockContext::default().with_frozen_client(&client_id, height, height);
+
+        let client_state = ctx.client_state(&client_id).unwrap();
+        assert_eq!(client_state.latest_height(), height);
+        assert!(client_state.confirm_not_frozen().is_err());
+    }
+
+    #[test]
+    fn test_client_ids() {
+        let client_id_1 = ClientId::new(mock_client_type(), 1).unwrap();
+        let client_id_0 = ClientId::new(mock_client_type(), 0).unwrap();
+        let height = Height::new(0, 42).unwrap();
+
+        let ctx = MockContext::default()
+            .with_client(&client_id_1, height)
+            .with_client(&client_id_0, height);
+
+        assert_eq!(ctx.client_ids(), vec![client_id_0, client_id_1]);
+    }
+
+    #[test]
+    fn test_events_with_seq() {
+        let client_id = ClientId::default();
+        let mut ctx = MockContext::default().with_client(&client_id, Height::new(0, 42).unwrap());
+
+        let update_client = |height: u64| {
+            MsgEnvelope::Client(ClientMsg::UpdateClient(MsgUpdateClient {
+                client_id: client_id.clone(),
+                header: MockHeader::new(Height::new(0, height).unwrap()).into(),
+                signer: get_dummy_account_id(),
+            }))
+        };
+
+        ctx.deliver(update_client(43)).unwrap();
+        // Clearing the flat event list does not reset the sequence numbers
+        ctx.events.clear();
+        ctx.deliver(update_client(44)).unwrap();
+
+        let events = ctx.events_with_seq();
+        assert_eq!(events.len(), 4);
+        for (expected_seq, (seq, _)) in events.iter().enumerate() {
+            assert_eq!(*seq, expected_seq as u64);
+        }
+
+        let updated_heights: Vec<_> = events
+            .iter()
+            .filter_map(|(_, event)| match event {
+                IbcEvent::UpdateClient(e) => Some(*e.consensus_height()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            updated_heights,
+            vec![Height::new(0, 43).unwrap(), Height::new(0, 44).unwrap()]
+        );
+    }
 }
@@ -7,10 +7,42 @@ use crate::applications::transfer::context::cosmos_adr028_escrow_address;
 use crate::applications::transfer::context::TokenTransferExecutionContext;
 use crate::applications::transfer::context::TokenTransferValidationContext;
 use crate::applications::transfer::error::TokenTransferError;
-use crate::applications::transfer::PrefixedCoin;
+use crate::applications::transfer::{Amount, PrefixedCoin, PrefixedDenom};
 use crate::core::ics24_host::identifier::{ChannelId, PortId};
 use crate::Signer;
 
+impl MockContext {
+    /// Returns the total supply of `denom` minted through the transfer application.
+    fn total_supply(&self, denom: &PrefixedDenom) -> Amount {
+        self.ibc_store
+            .lock()
+            .total_supply
+            .get(denom)
+            .copied()
+            .unwrap_or_else(|| 0u64.into())
+    }
+
+    /// Returns the total supply of the coin's denom after minting the coin.
+    fn supply_after_mint(&self, coin: &PrefixedCoin) -> Result<Amount, TokenTransferError> {
+        self.total_supply(&coin.denom)
+            .checked_add(coin.amount)
+            .ok_or(TokenTransferError::AmountOverflow)
+    }
+
+    /// Returns the total supply of the coin's denom after burning the coin,
+    /// failing if more than the minted supply would be burnt.
+    fn supply_after_burn(&self, coin: &PrefixedCoin) -> Result<Amount, TokenTransferError> {
+        let supply = self.total_supply(&coin.denom);
+        supply
+            .checked_sub(coin.amount)
+            .ok_or_else(|| TokenTransferError::InsufficientSupply {
+                denom: coin.denom.clone(),
+                supply,
+                requested: coin.amount,
+            })
+    }
+}
+
 impl TokenTransferValidationContext for MockContext {
     type AccountId = Signer;
 
@@ -47,17 +79,33 @@ impl TokenTransferValidationContext for MockContext {
     fn mint_coins_validate(
         &self,
         _account: &Self::AccountId,
-        _coin: &PrefixedCoin,
+        coin: &PrefixedCoin,
     ) -> Result<(), TokenTransferError> {
-        Ok(())
+        self.supply_after_mint(coin).map(|_| ())
     }
 
     fn burn_coins_validate(
         &self,
         _account: &Self::AccountId,
-        _coin: &PrefixedCoin,
+        coin: &PrefixedCoin,
     ) -> Result<(), TokenTransferError> {
-        Ok(())
+        self.supply_after_burn(coin).map(|_| ())
+    }
+
+    fn get_escrowed_amount(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        denom: &PrefixedDenom,
+    ) -> Result<Option<Amount>, TokenTransferError> {
+        Ok(Some(
+            self.ibc_store
+                .lock()
+                .escrowed_amounts
+                .get(&(port_id.clone(), channel_id.clone(), denom.clone()))
+                .copied()
+                .unwrap_or_else(|| 0u64.into()),
+        ))
     }
 }
 
@@ -74,16 +122,40 @@ impl TokenTransferExecutionContext for MockContext {
     fn mint_coins_execute(
         &mut self,
         _account: &Self::AccountId,
-        _coin: &PrefixedCoin,
+        coin: &PrefixedCoin,
     ) -> Result<(), TokenTransferError> {
+        let supply = self.supply_after_mint(coin)?;
+        self.ibc_store
+            .lock()
+            .total_supply
+            .insert(coin.denom.clone(), supply);
         Ok(())
     }
 
     fn burn_coins_execute(
         &mut self,
         _account: &Self::AccountId,
-        _coin: &PrefixedCoin,
+        coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        let supply = self.supply_after_burn(coin)?;
+        self.ibc_store
+            .lock()
+            .total_supply
+            .insert(coin.denom.clone(), supply);
+        Ok(())
+    }
+
+    fn store_escrowed_amount(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        denom: &PrefixedDenom,
+        amount: Amount,
     ) -> Result<(), TokenTransferError> {
+        self.ibc_store
+            .lock()
+            .escrowed_amounts
+            .insert((port_id.clone(), channel_id.clone(), denom.clone()), amount);
         Ok(())
     }
 }
@@ -2,7 +2,7 @@
 
 use crate::prelude::*;
 
-use super::{AnyClientState, AnyConsensusState, MockClientRecord, MockContext};
+use super::{AnyClientState, AnyConsensusState, MockClientRecord, MockContext, StoreDiff};
 use crate::clients::ics07_tendermint::CommonContext as TmCommonContext;
 use crate::clients::ics07_tendermint::ValidationContext as TmValidationContext;
 use crate::core::ics02_client::error::ClientError;
@@ -150,4 +150,70 @@ impl ClientExecutionContext for MockContext {
 
         Ok(())
     }
+
+    fn delete_consensus_state(
+        &mut self,
+        consensus_state_path: ClientConsensusStatePath,
+    ) -> Result<(), ContextError> {
+        let height = Height::new(consensus_state_path.epoch, consensus_state_path.height)?;
+        let client_id = consensus_state_path.client_id;
+
+        let mut ibc_store = self.ibc_store.lock();
+        if let Some(client_record) = ibc_store.clients.get_mut(&client_id) {
+            client_record.consensus_states.remove(&height);
+        }
+
+        let processed_key = (client_id, height);
+        ibc_store.client_processed_times.remove(&processed_key);
+        ibc_store.client_processed_heights.remove(&processed_key);
+
+        Ok(())
+    }
+
+    fn is_pruning_enabled(&self) -> bool {
+        self.pruning_enabled
+    }
+
+    fn consensus_states_for_pruning(
+        &self,
+        client_id: &ClientId,
+    ) -> Result<(Timestamp, Vec<(Height, Timestamp)>), ContextError> {
+        let host_timestamp = ValidationContext::host_timestamp(self)?;
+        let update_times = ValidationContext::consensus_state_heights(self, client_id)?
+            .into_iter()
+            .filter_map(|height| {
+                ValidationContext::client_update_time(self, client_id, &height)
+                    .ok()
+                    .map(|update_time| (height, update_time))
+            })
+            .collect();
+
+        Ok((host_timestamp, update_times))
+    }
+
+    fn on_store_client_state(
+        &mut self,
+        client_state_path: &ClientStatePath,
+    ) -> Result<(), ContextError> {
+        let client_state = ValidationContext::client_state(self, &client_state_path.0)?;
+        self.store_diffs.push(StoreDiff::ClientState(
+            client_state_path.clone(),
+            client_state,
+        ));
+
+        Ok(())
+    }
+
+    fn on_store_consensus_state(
+        &mut self,
+        consensus_state_path: &ClientConsensusStatePath,
+    ) -> Result<(), ContextError> {
+        let consensus_state = ValidationContext::consensus_state(self, consensus_state_path)?;
+        self.store_diffs.push(StoreDiff::ConsensusState(
+            consensus_state_path.clone(),
+            consensus_state,
+        ));
+
+        Ok(())
+    }
 }
@@ -22,12 +22,24 @@ use crate::Height;
 
 use super::context::AnyConsensusState;
 
+/// Returns the identifier of the first of the two mock host chains that tests
+/// commonly set up, i.e. `mockgaiaA-1`.
+pub fn mock_chain_id_a() -> ChainId {
+    ChainId::new("mockgaiaA", 1).expect("Never fails")
+}
+
+/// Returns the identifier of the second of the two mock host chains that tests
+/// commonly set up, i.e. `mockgaiaB-1`.
+pub fn mock_chain_id_b() -> ChainId {
+    ChainId::new("mockgaiaB", 1).expect("Never fails")
+}
+
 /// Defines the different types of host chains that a mock context can emulate.
 /// The variants are as follows:
 /// - `Mock` defines that the context history consists of `MockHeader` blocks.
 /// - `SyntheticTendermint`: the context has synthetically-generated Tendermint (light) blocks.
 /// See also the `HostBlock` enum to get more insights into the underlying block type.
-#[derive(Clone, Debug, Copy)]
+#[derive(Clone, Debug, Copy, PartialEq, Eq)]
 pub enum HostType {
     Mock,
     SyntheticTendermint,
@@ -185,3 +197,16 @@ impl From<HostBlock> for Any {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mock_chain_ids() {
+        assert_eq!(mock_chain_id_a(), ChainId::new("mockgaiaA", 1).unwrap());
+        assert_eq!(mock_chain_id_b(), ChainId::new("mockgaiaB", 1).unwrap());
+        assert_eq!(mock_chain_id_a().as_str(), "mockgaiaA-1");
+        assert_eq!(mock_chain_id_b().as_str(), "mockgaiaB-1");
+    }
+}
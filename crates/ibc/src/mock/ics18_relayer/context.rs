@@ -29,11 +29,11 @@ mod tests {
     use crate::core::ics02_client::client_state::ClientStateCommon;
     use crate::core::ics02_client::msgs::update_client::MsgUpdateClient;
     use crate::core::ics02_client::msgs::ClientMsg;
-    use crate::core::ics24_host::identifier::{ChainId, ClientId};
+    use crate::core::ics24_host::identifier::ClientId;
     use crate::core::MsgEnvelope;
     use crate::mock::client_state::client_type as mock_client_type;
     use crate::mock::context::MockContext;
-    use crate::mock::host::{HostBlock, HostType};
+    use crate::mock::host::{mock_chain_id_a, mock_chain_id_b, HostBlock, HostType};
     use crate::mock::ics18_relayer::context::RelayerContext;
     use crate::mock::ics18_relayer::error::RelayerError;
     use crate::prelude::*;
@@ -100,8 +100,8 @@ mod tests {
         let client_on_a_for_b = ClientId::new(tm_client_type(), 0).unwrap();
         let client_on_b_for_a = ClientId::new(mock_client_type(), 0).unwrap();
 
-        let chain_id_a = ChainId::new("mockgaiaA", 1).unwrap();
-        let chain_id_b = ChainId::new("mockgaiaB", 1).unwrap();
+        let chain_id_a = mock_chain_id_a();
+        let chain_id_b = mock_chain_id_b();
 
         // Create two mock contexts, one for each chain.
         let mut ctx_a =
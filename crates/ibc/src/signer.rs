@@ -30,3 +30,23 @@ impl AsRef<str> for Signer {
         self.0.as_str()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashSet;
+
+    #[test]
+    fn signer_as_hash_set_key() {
+        let alice = Signer::from("alice".to_string());
+        let bob = Signer::from("bob".to_string());
+
+        let allowed_signers: HashSet<Signer> = [alice.clone()].into_iter().collect();
+
+        assert!(allowed_signers.contains(&alice));
+        assert!(allowed_signers.contains(&Signer::from("alice".to_string())));
+        assert!(!allowed_signers.contains(&bob));
+        assert!(alice < bob);
+    }
+}
@@ -7,6 +7,8 @@ use crate::core::ics04_channel::packet::Packet;
 use crate::core::ics04_channel::Version;
 use crate::core::ics24_host::identifier::{ChannelId, ConnectionId, PortId};
 use crate::core::router::{Module, ModuleExtras};
+use crate::core::{validate, MsgEnvelope, RouterError};
+use crate::mock::context::MockContext;
 use crate::prelude::*;
 use crate::signer::Signer;
 
@@ -46,15 +48,73 @@ pub fn get_dummy_bech32_account() -> String {
     "cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng".to_string()
 }
 
+/// Runs `validate` twice on `msg`, and asserts that both runs agree and that
+/// `ctx` is left untouched, since validation must be free of side effects.
+/// Returns the validation result.
+pub fn assert_validate_idempotent(ctx: &MockContext, msg: MsgEnvelope) -> Result<(), RouterError> {
+    let snapshot = ctx.clone();
+
+    let first = validate(ctx, msg.clone());
+    let second = validate(ctx, msg);
+
+    assert_eq!(
+        format!("{first:?}"),
+        format!("{second:?}"),
+        "validation is not deterministic"
+    );
+    assert!(*ctx == snapshot, "validation mutated the context");
+
+    first
+}
+
 pub fn get_dummy_transfer_module() -> DummyTransferModule {
-    DummyTransferModule
+    DummyTransferModule::new()
+}
+
+/// A packet callback invoked on a [`DummyTransferModule`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PacketCallback {
+    Recv(Packet),
+    Acknowledgement(Packet, Acknowledgement),
+    Timeout(Packet),
 }
+
 #[derive(Debug)]
-pub struct DummyTransferModule;
+pub struct DummyTransferModule {
+    packet_callbacks: Vec<PacketCallback>,
+    chan_open_try_version: Option<Version>,
+    chan_open_try_extras: ModuleExtras,
+}
 
 impl DummyTransferModule {
     pub fn new() -> Self {
-        Self
+        Self {
+            packet_callbacks: Vec::new(),
+            chan_open_try_version: None,
+            chan_open_try_extras: ModuleExtras::empty(),
+        }
+    }
+
+    /// Sets the version returned by `on_chan_open_try_execute`, which
+    /// otherwise echoes the counterparty version.
+    pub fn with_chan_open_try_version(self, version: Version) -> Self {
+        Self {
+            chan_open_try_version: Some(version),
+            ..self
+        }
+    }
+
+    /// Sets the extras returned by `on_chan_open_try_execute`.
+    pub fn with_chan_open_try_extras(self, extras: ModuleExtras) -> Self {
+        Self {
+            chan_open_try_extras: extras,
+            ..self
+        }
+    }
+
+    /// Returns the packet callbacks executed on this module, in invocation order.
+    pub fn packet_callbacks(&self) -> &[PacketCallback] {
+        &self.packet_callbacks
     }
 }
 
@@ -110,14 +170,22 @@ impl Module for DummyTransferModule {
         _counterparty: &Counterparty,
         counterparty_version: &Version,
     ) -> Result<(ModuleExtras, Version), ChannelError> {
-        Ok((ModuleExtras::empty(), counterparty_version.clone()))
+        let version = self
+            .chan_open_try_version
+            .clone()
+            .unwrap_or_else(|| counterparty_version.clone());
+
+        Ok((self.chan_open_try_extras.clone(), version))
     }
 
     fn on_recv_packet_execute(
         &mut self,
-        _packet: &Packet,
+        packet: &Packet,
         _relayer: &Signer,
     ) -> (ModuleExtras, Acknowledgement) {
+        self.packet_callbacks
+            .push(PacketCallback::Recv(packet.clone()));
+
         (
             ModuleExtras::empty(),
             Acknowledgement::try_from(vec![1u8]).expect("Never fails"),
@@ -134,9 +202,12 @@ impl Module for DummyTransferModule {
 
     fn on_timeout_packet_execute(
         &mut self,
-        _packet: &Packet,
+        packet: &Packet,
         _relayer: &Signer,
     ) -> (ModuleExtras, Result<(), PacketError>) {
+        self.packet_callbacks
+            .push(PacketCallback::Timeout(packet.clone()));
+
         (ModuleExtras::empty(), Ok(()))
     }
 
@@ -151,10 +222,43 @@ impl Module for DummyTransferModule {
 
     fn on_acknowledgement_packet_execute(
         &mut self,
-        _packet: &Packet,
-        _acknowledgement: &Acknowledgement,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
         _relayer: &Signer,
     ) -> (ModuleExtras, Result<(), PacketError>) {
+        self.packet_callbacks.push(PacketCallback::Acknowledgement(
+            packet.clone(),
+            acknowledgement.clone(),
+        ));
+
         (ModuleExtras::empty(), Ok(()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dummy_transfer_module_records_recv() {
+        let mut module = DummyTransferModule::new();
+        let packet = Packet::default();
+
+        let (_, ack) = module.on_recv_packet_execute(&packet, &get_dummy_account_id());
+        assert_eq!(
+            module.packet_callbacks(),
+            &[PacketCallback::Recv(packet.clone())]
+        );
+
+        let (_, res) =
+            module.on_acknowledgement_packet_execute(&packet, &ack, &get_dummy_account_id());
+        assert!(res.is_ok());
+        assert_eq!(
+            module.packet_callbacks(),
+            &[
+                PacketCallback::Recv(packet.clone()),
+                PacketCallback::Acknowledgement(packet, ack)
+            ]
+        );
+    }
+}
@@ -0,0 +1,541 @@
+//! End-to-end tests of the token transfer packet flow between a pair of mock
+//! chains connected by an open token transfer channel: sending on chain A,
+//! receiving on chain B, and acknowledging or timing out back on chain A.
+
+use ibc::applications::transfer::context::{
+    on_acknowledgement_packet_execute, on_acknowledgement_packet_validate,
+    on_chan_open_init_execute, on_chan_open_init_validate, on_chan_open_try_execute,
+    on_chan_open_try_validate, on_recv_packet_execute, on_timeout_packet_execute,
+    on_timeout_packet_validate, TokenTransferValidationContext,
+};
+use ibc::applications::transfer::error::TokenTransferError;
+use ibc::applications::transfer::msgs::transfer::MsgTransfer;
+use ibc::applications::transfer::packet::PacketData;
+use ibc::applications::transfer::{module_id, send_transfer, Amount, BaseCoin, PrefixedDenom};
+use ibc::core::events::IbcEvent;
+use ibc::core::ics02_client::msgs::update_client::MsgUpdateClient;
+use ibc::core::ics02_client::msgs::ClientMsg;
+use ibc::core::ics03_connection::connection::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::ics03_connection::version::get_compatible_versions;
+use ibc::core::ics04_channel::acknowledgement::Acknowledgement;
+use ibc::core::ics04_channel::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::ics04_channel::error::{ChannelError, PacketError};
+use ibc::core::ics04_channel::msgs::acknowledgement::MsgAcknowledgement;
+use ibc::core::ics04_channel::msgs::recv_packet::MsgRecvPacket;
+use ibc::core::ics04_channel::msgs::timeout::MsgTimeout;
+use ibc::core::ics04_channel::msgs::PacketMsg;
+use ibc::core::ics04_channel::packet::Packet;
+use ibc::core::ics04_channel::timeout::TimeoutHeight;
+use ibc::core::ics04_channel::Version;
+use ibc::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::router::{Module, ModuleExtras};
+use ibc::core::timestamp::{Timestamp, ZERO_DURATION};
+use ibc::core::MsgEnvelope;
+use ibc::mock::client_state::client_type as mock_client_type;
+use ibc::mock::context::MockContext;
+use ibc::mock::host::{mock_chain_id_a, mock_chain_id_b, HostType};
+use ibc::test_utils::{get_dummy_account_id, get_dummy_bech32_account, get_dummy_proof};
+use ibc::Height;
+use ibc::Signer;
+
+/// The token transfer application, bound to the transfer port of a
+/// [`MockContext`]. It operates on a context that shares the IBC store of the
+/// host it is installed on, so that escrows, mints and burns are visible there.
+#[derive(Debug)]
+struct MockTransferModule {
+    ctx: MockContext,
+}
+
+impl MockTransferModule {
+    fn new(host: &MockContext) -> Self {
+        let mut ctx = MockContext::default();
+        ctx.ibc_store = host.ibc_store_share();
+        Self { ctx }
+    }
+}
+
+fn channel_error(e: TokenTransferError) -> ChannelError {
+    ChannelError::AppModule {
+        description: e.to_string(),
+    }
+}
+
+fn packet_error(e: TokenTransferError) -> PacketError {
+    PacketError::AppModule {
+        description: e.to_string(),
+    }
+}
+
+impl Module for MockTransferModule {
+    fn on_chan_open_init_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<Version, ChannelError> {
+        on_chan_open_init_validate(
+            &self.ctx,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        )
+        .map_err(channel_error)?;
+
+        Ok(version.clone())
+    }
+
+    fn on_chan_open_init_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        on_chan_open_init_execute(
+            &mut self.ctx,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        )
+        .map_err(channel_error)
+    }
+
+    fn on_chan_open_try_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        on_chan_open_try_validate(
+            &self.ctx,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            counterparty_version,
+        )
+        .map_err(channel_error)?;
+
+        Ok(counterparty_version.clone())
+    }
+
+    fn on_chan_open_try_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        on_chan_open_try_execute(
+            &mut self.ctx,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            counterparty_version,
+        )
+        .map_err(channel_error)
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        packet: &Packet,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Acknowledgement) {
+        on_recv_packet_execute(&mut self.ctx, packet)
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        on_acknowledgement_packet_validate(&self.ctx, packet, acknowledgement, relayer)
+            .map_err(packet_error)
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        let (extras, res) =
+            on_acknowledgement_packet_execute(&mut self.ctx, packet, acknowledgement, relayer);
+        (extras, res.map_err(packet_error))
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        on_timeout_packet_validate(&self.ctx, packet, relayer).map_err(packet_error)
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        let (extras, res) = on_timeout_packet_execute(&mut self.ctx, packet, relayer);
+        (extras, res.map_err(packet_error))
+    }
+}
+
+/// Two mock chains, A and B, each with a client of the other, an open
+/// connection, and an open unordered transfer channel between them. The
+/// methods act as the user and the relayer would, one step at a time, and
+/// clear the events of the chains they submit to, so that each step's events
+/// can be inspected on their own.
+struct MockNetwork {
+    ctx_a: MockContext,
+    ctx_b: MockContext,
+    client_id_on_a: ClientId,
+    client_id_on_b: ClientId,
+    chan_id_on_a: ChannelId,
+    chan_id_on_b: ChannelId,
+}
+
+impl MockNetwork {
+    fn new() -> Self {
+        let client_id = ClientId::new(mock_client_type(), 0).expect("Never fails");
+        let conn_id = ConnectionId::new(0);
+        let chan_id_on_a = ChannelId::new(0);
+        let chan_id_on_b = ChannelId::new(1);
+
+        let ctx_a = MockContext::new(
+            mock_chain_id_a(),
+            HostType::Mock,
+            5,
+            Height::new(1, 10).expect("Never fails"),
+        );
+        let ctx_b = MockContext::new(
+            mock_chain_id_b(),
+            HostType::Mock,
+            5,
+            Height::new(1, 20).expect("Never fails"),
+        );
+
+        let conn_end = ConnectionEnd::new(
+            ConnectionState::Open,
+            client_id.clone(),
+            ConnectionCounterparty::new(
+                client_id.clone(),
+                Some(conn_id.clone()),
+                Default::default(),
+            ),
+            get_compatible_versions(),
+            ZERO_DURATION,
+        )
+        .expect("Never fails");
+
+        let chan_end = |chan_id_on_counterparty: &ChannelId| {
+            ChannelEnd::new(
+                State::Open,
+                Order::Unordered,
+                Counterparty::new(PortId::transfer(), Some(chan_id_on_counterparty.clone())),
+                vec![conn_id.clone()],
+                Version::new("ics20-1".to_string()),
+            )
+            .expect("Never fails")
+        };
+
+        let ctx_a = ctx_a
+            .with_client(&client_id, ctx_b.latest_height())
+            .with_connection(conn_id.clone(), conn_end.clone())
+            .with_channel(
+                PortId::transfer(),
+                chan_id_on_a.clone(),
+                chan_end(&chan_id_on_b),
+            )
+            .with_send_sequence(PortId::transfer(), chan_id_on_a.clone(), 1.into());
+        let ctx_b = ctx_b
+            .with_client(&client_id, ctx_a.latest_height())
+            .with_connection(conn_id, conn_end)
+            .with_channel(
+                PortId::transfer(),
+                chan_id_on_b.clone(),
+                chan_end(&chan_id_on_a),
+            )
+            .with_recv_sequence(PortId::transfer(), chan_id_on_b.clone(), 1.into());
+
+        let mut network = Self {
+            ctx_a,
+            ctx_b,
+            client_id_on_a: client_id.clone(),
+            client_id_on_b: client_id,
+            chan_id_on_a,
+            chan_id_on_b,
+        };
+
+        for ctx in [&mut network.ctx_a, &mut network.ctx_b] {
+            let module = MockTransferModule::new(ctx);
+            ctx.add_route(module_id(), module).expect("Never fails");
+            ctx.bind_port(PortId::transfer(), module_id());
+        }
+
+        network
+    }
+
+    /// Transfers the dummy token amount from A to B, and returns the packet
+    /// that was sent.
+    fn send_transfer(&mut self, timeout_height_on_b: TimeoutHeight) -> Packet {
+        let msg = MsgTransfer {
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: self.chan_id_on_a.clone(),
+            packet_data: dummy_packet_data(),
+            timeout_height_on_b,
+            timeout_timestamp_on_b: Timestamp::none(),
+        };
+
+        self.ctx_a.events.clear();
+        send_transfer(&mut self.ctx_a, msg).expect("transfer must succeed");
+
+        self.ctx_a
+            .events
+            .iter()
+            .find_map(|event| match event {
+                IbcEvent::SendPacket(e) => Some(Packet {
+                    seq_on_a: *e.seq_on_a(),
+                    port_id_on_a: e.port_id_on_a().clone(),
+                    chan_id_on_a: e.chan_id_on_a().clone(),
+                    port_id_on_b: e.port_id_on_b().clone(),
+                    chan_id_on_b: e.chan_id_on_b().clone(),
+                    data: e.packet_data().to_vec(),
+                    timeout_height_on_b: *e.timeout_height_on_b(),
+                    timeout_timestamp_on_b: *e.timeout_timestamp_on_b(),
+                }),
+                _ => None,
+            })
+            .expect("a send packet event must be emitted")
+    }
+
+    /// Updates the client of A on B to the latest height of A, after A
+    /// produced a new block, and returns that height.
+    fn update_client_on_b(&mut self) -> Height {
+        self.ctx_a.advance_host_chain_height();
+        update_client(&self.ctx_a, &mut self.ctx_b, &self.client_id_on_b)
+    }
+
+    /// Updates the client of B on A to the latest height of B, after B
+    /// produced a new block, and returns that height.
+    fn update_client_on_a(&mut self) -> Height {
+        self.ctx_b.advance_host_chain_height();
+        update_client(&self.ctx_b, &mut self.ctx_a, &self.client_id_on_a)
+    }
+
+    /// Relays `packet` to B, and returns the acknowledgement written by B.
+    fn recv_packet_on_b(&mut self, packet: Packet) -> Acknowledgement {
+        self.ctx_b.events.clear();
+        let proof_height_on_a = self.update_client_on_b();
+
+        let msg = MsgRecvPacket {
+            packet,
+            proof_commitment_on_a: get_dummy_proof().try_into().expect("Never fails"),
+            proof_height_on_a,
+            signer: get_dummy_account_id(),
+        };
+        self.ctx_b
+            .deliver(MsgEnvelope::Packet(PacketMsg::Recv(msg)))
+            .expect("receiving the packet must succeed");
+
+        self.ctx_b
+            .events
+            .iter()
+            .find_map(|event| match event {
+                IbcEvent::WriteAcknowledgement(e) => Some(e.acknowledgement().clone()),
+                _ => None,
+            })
+            .expect("an acknowledgement must be written")
+    }
+
+    /// Relays the `acknowledgement` of `packet` back to A.
+    fn ack_packet_on_a(&mut self, packet: Packet, acknowledgement: Acknowledgement) {
+        self.ctx_a.events.clear();
+        let proof_height_on_b = self.update_client_on_a();
+
+        let msg = MsgAcknowledgement {
+            packet,
+            acknowledgement,
+            proof_acked_on_b: get_dummy_proof().try_into().expect("Never fails"),
+            proof_height_on_b,
+            signer: get_dummy_account_id(),
+        };
+        self.ctx_a
+            .deliver(MsgEnvelope::Packet(PacketMsg::Ack(msg)))
+            .expect("acknowledging the packet must succeed");
+    }
+
+    /// Proves to A that B did not receive `packet` before it timed out.
+    fn timeout_packet_on_a(&mut self, packet: Packet) {
+        self.ctx_a.events.clear();
+        let proof_height_on_b = self.update_client_on_a();
+
+        let msg = MsgTimeout {
+            next_seq_recv_on_b: packet.seq_on_a,
+            packet,
+            proof_unreceived_on_b: get_dummy_proof().try_into().expect("Never fails"),
+            proof_height_on_b,
+            signer: get_dummy_account_id(),
+        };
+        self.ctx_a
+            .deliver(MsgEnvelope::Packet(PacketMsg::Timeout(msg)))
+            .expect("timing out the packet must succeed");
+    }
+
+    /// Returns the amount of `denom` escrowed on A for the transfer channel.
+    fn escrowed_on_a(&self, denom: &PrefixedDenom) -> Amount {
+        self.ctx_a
+            .get_escrowed_amount(&PortId::transfer(), &self.chan_id_on_a, denom)
+            .expect("Never fails")
+            .expect("the mock context tracks escrow balances")
+    }
+
+    /// Returns the supply of `denom` minted on B.
+    fn supply_on_b(&self, denom: &PrefixedDenom) -> Option<Amount> {
+        self.ctx_b.ibc_store.lock().total_supply.get(denom).copied()
+    }
+}
+
+/// The data of the transfers sent by [`MockNetwork::send_transfer`].
+fn dummy_packet_data() -> PacketData {
+    let address: Signer = get_dummy_bech32_account().into();
+
+    PacketData {
+        token: BaseCoin {
+            denom: "uatom".parse().expect("Never fails"),
+            amount: 10u64.into(),
+        }
+        .into(),
+        sender: address.clone(),
+        receiver: address,
+        memo: "".to_string().into(),
+    }
+}
+
+/// Submits the latest header of `src` to the client `client_id` on `dst`, and
+/// returns the height of that header.
+fn update_client(src: &MockContext, dst: &mut MockContext, client_id: &ClientId) -> Height {
+    let header = src.query_latest_header().expect("Never fails");
+    let height = header.height();
+
+    let msg = MsgUpdateClient {
+        client_id: client_id.clone(),
+        header: header.into(),
+        signer: get_dummy_account_id(),
+    };
+    dst.deliver(MsgEnvelope::Client(ClientMsg::UpdateClient(msg)))
+        .expect("updating the client must succeed");
+
+    height
+}
+
+fn has_event(ctx: &MockContext, predicate: impl Fn(&IbcEvent) -> bool) -> bool {
+    ctx.events.iter().any(predicate)
+}
+
+fn has_packet_commitments(ctx: &MockContext) -> bool {
+    ctx.ibc_store
+        .lock()
+        .packet_commitment
+        .values()
+        .flat_map(|commitments| commitments.values())
+        .any(|commitments| !commitments.is_empty())
+}
+
+#[test]
+fn transfer_is_received_and_acknowledged() {
+    let mut network = MockNetwork::new();
+    let token = dummy_packet_data().token;
+    let voucher_denom: PrefixedDenom = format!("transfer/{}/{}", network.chan_id_on_b, token.denom)
+        .parse()
+        .unwrap();
+    let timeout_height_on_b = network.ctx_b.latest_height().add(100);
+
+    // A sends: the tokens are escrowed
+    let packet = network.send_transfer(TimeoutHeight::At(timeout_height_on_b));
+    assert_eq!(network.escrowed_on_a(&token.denom), token.amount);
+    assert!(has_event(&network.ctx_a, |e| matches!(
+        e,
+        IbcEvent::SendPacket(_)
+    )));
+
+    // B receives: vouchers are minted and a successful acknowledgement is written
+    let acknowledgement = network.recv_packet_on_b(packet.clone());
+    assert_eq!(network.supply_on_b(&voucher_denom), Some(token.amount));
+    assert!(has_event(&network.ctx_b, |e| matches!(
+        e,
+        IbcEvent::ReceivePacket(_)
+    )));
+    assert!(has_event(&network.ctx_b, |e| matches!(
+        e,
+        IbcEvent::Module(module_event) if module_event.kind == "fungible_token_packet"
+    )));
+
+    // A processes the acknowledgement: the tokens stay in escrow
+    network.ack_packet_on_a(packet, acknowledgement);
+    assert_eq!(network.escrowed_on_a(&token.denom), token.amount);
+    assert!(has_event(&network.ctx_a, |e| matches!(
+        e,
+        IbcEvent::AcknowledgePacket(_)
+    )));
+    assert!(!has_packet_commitments(&network.ctx_a));
+}
+
+#[test]
+fn transfer_times_out_and_is_refunded() {
+    let mut network = MockNetwork::new();
+    let token = dummy_packet_data().token;
+    let voucher_denom: PrefixedDenom = format!("transfer/{}/{}", network.chan_id_on_b, token.denom)
+        .parse()
+        .unwrap();
+    let timeout_height_on_b = network.ctx_b.latest_height().increment();
+
+    // A sends: the tokens are escrowed
+    let packet = network.send_transfer(TimeoutHeight::At(timeout_height_on_b));
+    assert_eq!(network.escrowed_on_a(&token.denom), token.amount);
+    assert!(has_packet_commitments(&network.ctx_a));
+
+    // B reaches the timeout height without the packet being relayed
+    while network.ctx_b.latest_height() < timeout_height_on_b {
+        network.ctx_b.advance_host_chain_height();
+    }
+
+    // A learns about a height of B past the timeout: the tokens are refunded
+    network.timeout_packet_on_a(packet);
+    assert_eq!(network.escrowed_on_a(&token.denom), Amount::from(0u64));
+    assert!(has_event(&network.ctx_a, |e| matches!(
+        e,
+        IbcEvent::TimeoutPacket(_)
+    )));
+    assert!(has_event(&network.ctx_a, |e| matches!(
+        e,
+        IbcEvent::Module(module_event) if module_event.kind == "timeout"
+    )));
+    assert!(!has_packet_commitments(&network.ctx_a));
+
+    // B never minted vouchers
+    assert_eq!(network.supply_on_b(&voucher_denom), None);
+}